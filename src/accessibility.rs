@@ -0,0 +1,62 @@
+use std::{
+    cell::Cell,
+    env,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+const UNSET: u8 = 0;
+const OFF: u8 = 1;
+const ON: u8 = 2;
+
+static MODE: AtomicU8 = AtomicU8::new(UNSET);
+
+thread_local! {
+    static PINNED: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Turns accessibility mode on or off, overriding the environment variable.
+///
+/// In accessibility mode no ASCII art is drawn, the screen is never cleared
+/// and boards are printed as plain lines of text, for screen readers.
+pub fn set_accessible(on: bool) {
+    if PINNED.get().is_some() {
+        PINNED.set(Some(on));
+        return;
+    }
+    MODE.store(if on { ON } else { OFF }, Ordering::Relaxed);
+}
+
+/// Whether accessibility mode is on.
+///
+/// Unless set_accessible was called, it's on if the environment variable
+/// MORRISTOWN_ACCESSIBLE is set to 1, TRUE, YES or ON.
+pub fn is_accessible() -> bool {
+    if let Some(on) = PINNED.get() {
+        return on;
+    }
+    match MODE.load(Ordering::Relaxed) {
+        ON => true,
+        OFF => false,
+        _ => {
+            let on = env::var("MORRISTOWN_ACCESSIBLE")
+                .map(|v| matches!(v.to_uppercase().as_str(), "1" | "TRUE" | "YES" | "ON"))
+                .unwrap_or(false);
+            // don't overwrite a concurrent set_accessible
+            let _ = MODE.compare_exchange(
+                UNSET,
+                if on { ON } else { OFF },
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+            on
+        }
+    }
+}
+
+/// Gives the current thread its own mode, ignoring the environment variable
+/// and so set_accessible there doesn't change other threads.
+///
+/// None goes back to the shared mode. Returns the previous pin.
+pub(crate) fn pin_accessible(on: Option<bool>) -> Option<bool> {
+    PINNED.replace(on)
+}
@@ -0,0 +1,87 @@
+/// A game state that can be searched for computer moves.
+pub trait GameTree: Sized {
+    type Move: Clone;
+
+    /// Legal moves from this state, empty if the game is over.
+    fn moves(&self) -> Vec<Self::Move>;
+
+    /// The state after making the move.
+    fn play(&self, m: &Self::Move) -> Self;
+
+    /// Whether it's the turn of the side trying to maximize the evaluation.
+    fn maximizing(&self) -> bool;
+}
+
+/// Scores the state by searching "depth" moves ahead.
+///
+/// "eval" scores a state from the maximizing side's point of view,
+/// and is used for finished games and when the depth runs out.
+pub fn minimax<S, F>(state: &S, depth: u32, eval: F) -> i32
+where
+    S: GameTree,
+    F: Fn(&S) -> i32,
+{
+    search(state, depth, i32::MIN, i32::MAX, &eval)
+}
+
+/// Finds the best move for whoever's turn it is, None if there are no moves.
+pub fn best_move<S, F>(state: &S, depth: u32, eval: F) -> Option<S::Move>
+where
+    S: GameTree,
+    F: Fn(&S) -> i32,
+{
+    let maximizing = state.maximizing();
+    let mut best: Option<(i32, S::Move)> = None;
+
+    for m in state.moves() {
+        let score = search(
+            &state.play(&m),
+            depth.saturating_sub(1),
+            i32::MIN,
+            i32::MAX,
+            &eval,
+        );
+        let better = match &best {
+            None => true,
+            Some((b, _)) => (maximizing && score > *b) || (!maximizing && score < *b),
+        };
+        if better {
+            best = Some((score, m));
+        }
+    }
+
+    best.map(|(_, m)| m)
+}
+
+fn search<S, F>(state: &S, depth: u32, mut alpha: i32, mut beta: i32, eval: &F) -> i32
+where
+    S: GameTree,
+    F: Fn(&S) -> i32,
+{
+    let moves = state.moves();
+    if depth == 0 || moves.is_empty() {
+        return eval(state);
+    }
+
+    if state.maximizing() {
+        let mut best = i32::MIN;
+        for m in &moves {
+            best = best.max(search(&state.play(m), depth - 1, alpha, beta, eval));
+            alpha = alpha.max(best);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    } else {
+        let mut best = i32::MAX;
+        for m in &moves {
+            best = best.min(search(&state.play(m), depth - 1, alpha, beta, eval));
+            beta = beta.min(best);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,76 @@
+use std::{thread, time::Duration};
+
+/// Plays a square wave tone of "freq" hertz for "duration".
+///
+/// Sound needs the "audio" feature and an "aplay" binary (ALSA).
+/// Otherwise this stays silent but still takes "duration", so game pacing is kept.
+pub fn play_tone(freq: f32, duration: Duration) {
+    if freq <= 0.0 || !sound(freq, duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Plays a short beep, like the console speaker in the originals.
+pub fn beep() {
+    play_tone(880.0, Duration::from_millis(150));
+}
+
+/// Plays tones one after another, a frequency of 0 is a rest.
+pub fn play_sequence(tones: &[(f32, Duration)]) {
+    for (freq, duration) in tones {
+        play_tone(*freq, *duration);
+    }
+}
+
+#[cfg(feature = "audio")]
+fn sound(freq: f32, duration: Duration) -> bool {
+    use std::{
+        io::Write,
+        process::{Command, Stdio},
+    };
+
+    const RATE: u32 = 8000;
+
+    let samples = (RATE as f32 * duration.as_secs_f32()) as usize;
+    let period = RATE as f32 / freq;
+    let wave: Vec<u8> = (0..samples)
+        .map(|i| {
+            if (i as f32 % period) < period / 2.0 {
+                160
+            } else {
+                96
+            }
+        })
+        .collect();
+
+    let child = Command::new("aplay")
+        .args([
+            "-q",
+            "-t",
+            "raw",
+            "-f",
+            "U8",
+            "-c",
+            "1",
+            "-r",
+            &RATE.to_string(),
+            "-",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let Ok(mut child) = child else {
+        return false;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(&wave);
+    }
+    matches!(child.wait(), Ok(status) if status.success())
+}
+
+#[cfg(not(feature = "audio"))]
+fn sound(_freq: f32, _duration: Duration) -> bool {
+    false
+}
@@ -0,0 +1,186 @@
+use crate::accessibility::is_accessible;
+
+/// Default width a banner has to fit in.
+pub const BANNER_WIDTH: usize = 70;
+
+const GLYPH_HEIGHT: usize = 7;
+
+/// 5x7 block letters, "#" marks a filled cell.
+#[rustfmt::skip]
+const FONT: &[(char, [&str; GLYPH_HEIGHT])] = &[
+    ('A', [".###.", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"]),
+    ('B', ["####.", "#...#", "#...#", "####.", "#...#", "#...#", "####."]),
+    ('C', [".###.", "#...#", "#....", "#....", "#....", "#...#", ".###."]),
+    ('D', ["####.", "#...#", "#...#", "#...#", "#...#", "#...#", "####."]),
+    ('E', ["#####", "#....", "#....", "####.", "#....", "#....", "#####"]),
+    ('F', ["#####", "#....", "#....", "####.", "#....", "#....", "#...."]),
+    ('G', [".###.", "#...#", "#....", "#.###", "#...#", "#...#", ".###."]),
+    ('H', ["#...#", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"]),
+    ('I', [".###.", "..#..", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('J', ["..###", "...#.", "...#.", "...#.", "...#.", "#..#.", ".##.."]),
+    ('K', ["#...#", "#..#.", "#.#..", "##...", "#.#..", "#..#.", "#...#"]),
+    ('L', ["#....", "#....", "#....", "#....", "#....", "#....", "#####"]),
+    ('M', ["#...#", "##.##", "#.#.#", "#.#.#", "#...#", "#...#", "#...#"]),
+    ('N', ["#...#", "#...#", "##..#", "#.#.#", "#..##", "#...#", "#...#"]),
+    ('O', [".###.", "#...#", "#...#", "#...#", "#...#", "#...#", ".###."]),
+    ('P', ["####.", "#...#", "#...#", "####.", "#....", "#....", "#...."]),
+    ('Q', [".###.", "#...#", "#...#", "#...#", "#.#.#", "#..#.", ".##.#"]),
+    ('R', ["####.", "#...#", "#...#", "####.", "#.#..", "#..#.", "#...#"]),
+    ('S', [".####", "#....", "#....", ".###.", "....#", "....#", "####."]),
+    ('T', ["#####", "..#..", "..#..", "..#..", "..#..", "..#..", "..#.."]),
+    ('U', ["#...#", "#...#", "#...#", "#...#", "#...#", "#...#", ".###."]),
+    ('V', ["#...#", "#...#", "#...#", "#...#", "#...#", ".#.#.", "..#.."]),
+    ('W', ["#...#", "#...#", "#...#", "#.#.#", "#.#.#", "#.#.#", ".#.#."]),
+    ('X', ["#...#", "#...#", ".#.#.", "..#..", ".#.#.", "#...#", "#...#"]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#..", "..#.."]),
+    ('Z', ["#####", "....#", "...#.", "..#..", ".#...", "#....", "#####"]),
+    ('0', [".###.", "#...#", "#..##", "#.#.#", "##..#", "#...#", ".###."]),
+    ('1', ["..#..", ".##..", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('2', [".###.", "#...#", "....#", "...#.", "..#..", ".#...", "#####"]),
+    ('3', ["#####", "...#.", "..#..", "...#.", "....#", "#...#", ".###."]),
+    ('4', ["...#.", "..##.", ".#.#.", "#..#.", "#####", "...#.", "...#."]),
+    ('5', ["#####", "#....", "####.", "....#", "....#", "#...#", ".###."]),
+    ('6', ["..##.", ".#...", "#....", "####.", "#...#", "#...#", ".###."]),
+    ('7', ["#####", "....#", "...#.", "..#..", ".#...", ".#...", ".#..."]),
+    ('8', [".###.", "#...#", "#...#", ".###.", "#...#", "#...#", ".###."]),
+    ('9', [".###.", "#...#", "#...#", ".####", "....#", "...#.", ".##.."]),
+    ('-', [".....", ".....", ".....", ".###.", ".....", ".....", "....."]),
+    ('!', ["..#..", "..#..", "..#..", "..#..", "..#..", ".....", "..#.."]),
+    ('?', [".###.", "#...#", "....#", "...#.", "..#..", ".....", "..#.."]),
+    ('.', [".....", ".....", ".....", ".....", ".....", ".....", "..#.."]),
+    (',', [".....", ".....", ".....", ".....", ".....", "..#..", ".#..."]),
+    ('\'', ["..#..", "..#..", ".....", ".....", ".....", ".....", "....."]),
+    (' ', [".....", ".....", ".....", ".....", ".....", ".....", "....."]),
+];
+
+/// Drawn for characters the font doesn't have, an empty box so they don't pass for a "?".
+#[rustfmt::skip]
+const MISSING_GLYPH: [&str; GLYPH_HEIGHT] =
+    ["#####", "#...#", "#...#", "#...#", "#...#", "#...#", "#####"];
+
+fn glyph(c: char) -> &'static [&'static str; GLYPH_HEIGHT] {
+    let c = c.to_ascii_uppercase();
+    FONT.iter()
+        .find(|(g, _)| *g == c)
+        .map_or(&MISSING_GLYPH, |(_, rows)| rows)
+}
+
+/// Splits the text in chunks of at most "max" characters, breaking at spaces where possible.
+fn wrap(text: &str, max: usize) -> Vec<String> {
+    let max = max.max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let word: Vec<char> = word.chars().collect();
+        for chunk in word.chunks(max) {
+            let chunk: String = chunk.iter().collect();
+            if line.is_empty() {
+                line = chunk;
+            } else if line.chars().count() + 1 + chunk.chars().count() <= max {
+                line.push(' ');
+                line.push_str(&chunk);
+            } else {
+                lines.push(std::mem::replace(&mut line, chunk));
+            }
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn banner_lines(name: &str, fill: Option<char>, width: usize) -> Vec<String> {
+    let mut out = Vec::new();
+
+    for text in wrap(name, (width + 1) / 6) {
+        let text_width = text.chars().count() * 6 - 1;
+        let indent = " ".repeat(width.saturating_sub(text_width) / 2);
+
+        for row in 0..GLYPH_HEIGHT {
+            let mut line = indent.clone();
+            for (i, c) in text.chars().enumerate() {
+                if i > 0 {
+                    line.push(' ');
+                }
+                let f = fill.unwrap_or(c.to_ascii_uppercase());
+                line.extend(
+                    glyph(c)[row]
+                        .chars()
+                        .map(|g| if g == '#' { f } else { ' ' }),
+                );
+            }
+            out.push(line.trim_end().to_string());
+        }
+        out.push(String::new());
+    }
+
+    out
+}
+
+/// Prints the name in large block letters, like the classic BANNER program.
+///
+/// Each letter is drawn with itself, and the banner is centered in BANNER_WIDTH columns.
+pub fn print_banner(name: &str) {
+    print_banner_with(name, None, BANNER_WIDTH);
+}
+
+/// Prints the name in large block letters, drawn with "fill" (or each letter itself if None),
+/// centered in "width" columns and wrapped onto more rows if it doesn't fit.
+///
+/// In accessibility mode the name is just printed as text.
+pub fn print_banner_with(name: &str, fill: Option<char>, width: usize) {
+    say!("{}", render_banner_with(name, fill, width));
+}
+
+/// The banner of print_banner as text.
+pub fn render_banner(name: &str) -> String {
+    render_banner_with(name, None, BANNER_WIDTH)
+}
+
+/// The banner of print_banner_with as text.
+pub fn render_banner_with(name: &str, fill: Option<char>, width: usize) -> String {
+    if is_accessible() {
+        return format!("\n{}\n", name.to_uppercase());
+    }
+    format!("\n{}", banner_lines(name, fill, width).join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accessibility;
+
+    #[test]
+    fn draws_letters_with_themselves() {
+        accessibility::pin_accessible(Some(false));
+        assert_eq!(
+            render_banner_with("hi", None, 11),
+            "\nH   H  III\nH   H   I\nH   H   I\nHHHHH   I\nH   H   I\nH   H   I\nH   H  III\n"
+        );
+    }
+
+    #[test]
+    fn draws_spaces_blank_and_unknown_characters_as_boxes() {
+        accessibility::pin_accessible(Some(false));
+        let banner = render_banner_with("I ~", Some('#'), 17);
+        assert_eq!(banner.lines().nth(1), Some(" ###        #####"));
+        assert_eq!(banner.lines().nth(2), Some("  #         #   #"));
+    }
+
+    #[test]
+    fn wraps_to_the_width() {
+        accessibility::pin_accessible(Some(false));
+        let banner = render_banner_with("AB CD", Some('*'), 11);
+        // a blank line, then two rows of letters each followed by a blank line
+        assert_eq!(banner.lines().count(), 2 * (GLYPH_HEIGHT + 1));
+        assert!(banner.lines().all(|l| l.chars().count() <= 11));
+    }
+
+    #[test]
+    fn prints_text_in_accessibility_mode() {
+        accessibility::pin_accessible(Some(true));
+        assert_eq!(render_banner_with("hi", None, 11), "\nHI\n");
+    }
+}
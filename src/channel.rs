@@ -0,0 +1,214 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+
+use crate::{
+    console::{self, Console, Hint},
+    quit, rng,
+};
+
+/// Text for the frontend to show, usually asking for an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PromptRequest {
+    /// Everything the game wrote since the last answer.
+    pub text: String,
+    /// What the answer should be, None if the game doesn't want one (it ended).
+    pub hint: Option<Hint>,
+}
+
+/// A console that hands prompts to another thread and waits for its answers.
+///
+/// For embedding games in GUI frontends, see spawn_game.
+pub struct ChannelConsole {
+    requests: Sender<PromptRequest>,
+    answers: Receiver<String>,
+    hint: Hint,
+    pending: String,
+}
+
+/// Creates a channel console, with the ends for the frontend:
+/// the receiver of prompts and the sender of answers.
+pub fn channel_console() -> (ChannelConsole, Receiver<PromptRequest>, Sender<String>) {
+    let (request_tx, request_rx) = mpsc::channel();
+    let (answer_tx, answer_rx) = mpsc::channel();
+    let console = ChannelConsole {
+        requests: request_tx,
+        answers: answer_rx,
+        hint: Hint::default(),
+        pending: String::new(),
+    };
+    (console, request_rx, answer_tx)
+}
+
+impl Console for ChannelConsole {
+    fn write(&mut self, text: &str) {
+        self.pending.push_str(text);
+    }
+
+    fn hint(&mut self, hint: &Hint) {
+        self.hint = hint.clone();
+    }
+
+    /// None once the frontend dropped its ends of the channels.
+    fn read_line(&mut self) -> Option<String> {
+        let request = PromptRequest {
+            text: std::mem::take(&mut self.pending),
+            hint: Some(std::mem::take(&mut self.hint)),
+        };
+        self.requests.send(request).ok()?;
+        self.answers.recv().ok()
+    }
+}
+
+impl Drop for ChannelConsole {
+    fn drop(&mut self) {
+        if !self.pending.is_empty() {
+            let _ = self.requests.send(PromptRequest {
+                text: std::mem::take(&mut self.pending),
+                hint: None,
+            });
+        }
+    }
+}
+
+/// Runs a game on its own thread with a channel console.
+///
+/// The frontend receives prompts and sends answers, the receiver disconnects when the game is over,
+/// also when it was quit with the quit word.
+///
+/// The game thread starts with the help text, quit word and random number state of the calling
+/// thread. Middleware and on_quit callbacks stay behind, add them inside "game".
+pub fn spawn_game<F>(game: F) -> (JoinHandle<()>, Receiver<PromptRequest>, Sender<String>)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let (channel, requests, answers) = channel_console();
+    let help = console::help_source();
+    let quit_word = quit::quit_word();
+    let seed = rng::state();
+    let handle = thread::spawn(move || {
+        console::set_help_source(help);
+        quit::set_quit_word(quit_word.as_deref());
+        rng::seed(seed);
+        console::set_console(Box::new(channel));
+        quit::run_game(game);
+        // dropping the channel console sends the rest of the output
+        console::reset_console();
+    });
+    (handle, requests, answers)
+}
+
+/// What a stepped game did since it was last resumed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// The game wrote "text" and waits for an answer.
+    NeedsInput { text: String, hint: Hint },
+    /// The game is over, "text" is the output after the last answer.
+    Finished { text: String },
+}
+
+/// A game that runs until it needs input and is then resumed with the answer.
+///
+/// The host drives the game step by step from any loop (web, GUI, tests),
+/// while the game itself is written with the usual blocking prompts.
+/// It runs on its own thread with a channel console, set up like in spawn_game.
+pub struct SteppedGame {
+    handle: Option<JoinHandle<()>>,
+    requests: Receiver<PromptRequest>,
+    answers: Sender<String>,
+}
+
+impl SteppedGame {
+    /// Starts the game and runs it until it first needs input or ends.
+    pub fn start<F>(game: F) -> (Self, Step)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (handle, requests, answers) = spawn_game(game);
+        let mut stepped = SteppedGame {
+            handle: Some(handle),
+            requests,
+            answers,
+        };
+        let step = stepped.next_step();
+        (stepped, step)
+    }
+
+    /// Gives the answer to the game and runs it until it needs input again or ends.
+    ///
+    /// Resuming a finished game just returns Finished again, without output.
+    /// If the game panicked, the panic is passed on here.
+    pub fn resume(&mut self, answer: &str) -> Step {
+        if self.answers.send(answer.to_string()).is_err() {
+            return self.finish(String::new());
+        }
+        self.next_step()
+    }
+
+    fn next_step(&mut self) -> Step {
+        match self.requests.recv() {
+            Ok(PromptRequest {
+                text,
+                hint: Some(hint),
+            }) => Step::NeedsInput { text, hint },
+            Ok(PromptRequest { text, hint: None }) => self.finish(text),
+            Err(_) => self.finish(String::new()),
+        }
+    }
+
+    fn finish(&mut self, text: String) -> Step {
+        if let Some(handle) = self.handle.take() {
+            if let Err(panic) = handle.join() {
+                std::panic::resume_unwind(panic);
+            }
+        }
+        Step::Finished { text }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    use super::*;
+    use crate::prompt_number;
+
+    #[test]
+    fn game_ends_when_the_frontend_stops_answering() {
+        let quit_ran = Arc::new(AtomicBool::new(false));
+        let flag = quit_ran.clone();
+        let (handle, requests, answers) = spawn_game(move || {
+            quit::on_quit(move || flag.store(true, Ordering::SeqCst));
+            prompt_number::<i32>("FIRST?");
+            prompt_number::<i32>("SECOND?");
+            unreachable!("the frontend hung up");
+        });
+
+        assert_eq!(requests.recv().unwrap().text, "FIRST?\n");
+        answers.send("1".to_string()).unwrap();
+        assert_eq!(requests.recv().unwrap().text, "SECOND?\n");
+        drop(answers);
+
+        assert!(handle.join().is_ok());
+        assert!(quit_ran.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn stepped_game_finishes_after_its_last_answer() {
+        let (mut game, step) = SteppedGame::start(|| {
+            let n = prompt_number::<i32>("N?");
+            say!("{}", n * 2);
+        });
+        assert!(matches!(step, Step::NeedsInput { text, .. } if text == "N?\n"));
+        assert_eq!(
+            game.resume("21"),
+            Step::Finished {
+                text: "42\n".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    cell::RefCell,
+    io::{self, BufRead, BufReader, Write},
+    net::TcpStream,
+    rc::Rc,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    console::{self, Console},
+    quit,
+};
+
+/// Longest message text sent at once, IRC lines are limited to 512 bytes.
+const MAX_MESSAGE: usize = 400;
+
+/// Pause between messages so the server doesn't kick the bot for flooding.
+const MESSAGE_DELAY: Duration = Duration::from_millis(300);
+
+/// A console playing over an IRC channel.
+///
+/// Every line of output is sent to the channel and messages in the channel are the answers.
+/// If the connection breaks the game is quit, run_irc then returns the error.
+pub struct IrcConsole {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    channel: String,
+    player: Option<String>,
+    pending: String,
+    error: Rc<RefCell<Option<io::Error>>>,
+}
+
+impl IrcConsole {
+    /// Connects to "server" (host:port) as "nick" and joins "channel".
+    pub fn connect(server: &str, nick: &str, channel: &str) -> io::Result<Self> {
+        let writer = TcpStream::connect(server)?;
+        let reader = BufReader::new(writer.try_clone()?);
+        let mut irc = IrcConsole {
+            reader,
+            writer,
+            channel: channel.to_string(),
+            player: None,
+            pending: String::new(),
+            error: Rc::default(),
+        };
+
+        irc.send(&format!("NICK {nick}"))?;
+        irc.send(&format!("USER {nick} 0 * :{nick}"))?;
+
+        // wait for the welcome before joining
+        loop {
+            let line = irc.receive()?.ok_or(io::ErrorKind::UnexpectedEof)?;
+            let (_, command, _) = parse(&line);
+            match command {
+                "001" => break,
+                "433" => return Err(io::Error::other(format!("nick {nick} is taken"))),
+                _ => {}
+            }
+        }
+        irc.send(&format!("JOIN {channel}"))?;
+
+        Ok(irc)
+    }
+
+    /// Only takes answers from this nick, otherwise anyone in the channel can answer.
+    pub fn player(mut self, nick: &str) -> Self {
+        self.player = Some(nick.to_string());
+        self
+    }
+
+    /// Keeps the first error, later ones usually just follow from it.
+    fn fail(&self, e: io::Error) {
+        self.error.borrow_mut().get_or_insert(e);
+    }
+
+    fn send(&mut self, line: &str) -> io::Result<()> {
+        self.writer.write_all(format!("{line}\r\n").as_bytes())
+    }
+
+    fn say(&mut self, text: &str) -> io::Result<()> {
+        let chars: Vec<char> = text.chars().collect();
+        for chunk in chars.chunks(MAX_MESSAGE) {
+            let chunk: String = chunk.iter().collect();
+            let msg = format!("PRIVMSG {} :{}", self.channel, chunk);
+            self.send(&msg)?;
+            thread::sleep(MESSAGE_DELAY);
+        }
+        Ok(())
+    }
+
+    /// Reads a line from the server, answering pings. None if the connection closed.
+    fn receive(&mut self) -> io::Result<Option<String>> {
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            let line = line.trim_end_matches(['\r', '\n']).to_string();
+            if let Some(token) = line.strip_prefix("PING") {
+                self.send(&format!("PONG{token}"))?;
+                continue;
+            }
+            return Ok(Some(line));
+        }
+    }
+
+    fn flush_pending(&mut self) -> io::Result<()> {
+        let pending = std::mem::take(&mut self.pending);
+        for line in pending.lines().filter(|l| !l.trim().is_empty()) {
+            self.say(line)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for IrcConsole {
+    fn drop(&mut self) {
+        let _ = self.flush_pending();
+        let _ = self.send("QUIT :GAME OVER");
+    }
+}
+
+/// Splits an IRC line into the sender's nick, the command and the trailing text.
+fn parse(line: &str) -> (&str, &str, &str) {
+    let (prefix, rest) = match line.strip_prefix(':') {
+        Some(l) => l.split_once(' ').unwrap_or((l, "")),
+        None => ("", line),
+    };
+    let nick = prefix.split('!').next().unwrap_or("");
+    let (command, params) = rest.split_once(' ').unwrap_or((rest, ""));
+    let text = match params.strip_prefix(':') {
+        Some(t) => t,
+        None => params.split_once(" :").map_or("", |(_, t)| t),
+    };
+    (nick, command, text)
+}
+
+impl Console for IrcConsole {
+    fn write(&mut self, text: &str) {
+        if self.error.borrow().is_some() {
+            return;
+        }
+        self.pending.push_str(text);
+        if let Some(i) = self.pending.rfind('\n') {
+            let rest = self.pending.split_off(i + 1);
+            if let Err(e) = self.flush_pending() {
+                self.fail(e);
+            }
+            self.pending = rest;
+        }
+    }
+
+    /// None once the connection is closed or broken, which quits the game.
+    fn read_line(&mut self) -> Option<String> {
+        if let Err(e) = self.flush_pending() {
+            self.fail(e);
+            return None;
+        }
+        loop {
+            let line = match self.receive() {
+                Ok(Some(line)) => line,
+                Ok(None) => {
+                    self.fail(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "the IRC server closed the connection",
+                    ));
+                    return None;
+                }
+                Err(e) => {
+                    self.fail(e);
+                    return None;
+                }
+            };
+            let (nick, command, text) = parse(&line);
+            let target = line.split(' ').nth(2).unwrap_or("");
+            if command != "PRIVMSG" || !target.eq_ignore_ascii_case(&self.channel) {
+                continue;
+            }
+            if self.player.as_deref().is_some_and(|p| p != nick) {
+                continue;
+            }
+            return Some(text.to_string());
+        }
+    }
+}
+
+/// Runs a game over IRC: connects, then plays "game" with the channel as its console.
+///
+/// Fails if connecting fails or the connection breaks during the game, which ends it.
+pub fn run_irc<F: FnOnce()>(server: &str, nick: &str, channel: &str, game: F) -> io::Result<()> {
+    let irc = IrcConsole::connect(server, nick, channel)?;
+    let error = irc.error.clone();
+    let previous = console::set_console(Box::new(irc));
+    quit::run_game(game);
+    // dropping the IRC console sends the rest of the output and quits
+    drop(console::set_console(previous));
+    match error.take() {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_messages() {
+        assert_eq!(
+            parse(":alice!~a@host PRIVMSG #games :YES PLEASE"),
+            ("alice", "PRIVMSG", "YES PLEASE")
+        );
+        assert_eq!(
+            parse(":alice!~a@host PRIVMSG #games :"),
+            ("alice", "PRIVMSG", "")
+        );
+        assert_eq!(
+            parse(":irc.example.net 001 bot :Welcome to IRC"),
+            ("irc.example.net", "001", "Welcome to IRC")
+        );
+    }
+
+    #[test]
+    fn parses_lines_without_prefix_or_text() {
+        assert_eq!(
+            parse("PING :irc.example.net"),
+            ("", "PING", "irc.example.net")
+        );
+        assert_eq!(parse(":bob JOIN #games"), ("bob", "JOIN", ""));
+        assert_eq!(parse("QUIT"), ("", "QUIT", ""));
+        assert_eq!(parse(":server"), ("server", "", ""));
+        assert_eq!(parse(""), ("", "", ""));
+    }
+}
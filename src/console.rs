@@ -0,0 +1,394 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Debug,
+    fs::{File, OpenOptions},
+    io::{self, IsTerminal, Write},
+    ops::RangeInclusive,
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use crate::accessibility::is_accessible;
+
+/// Where prompts and other output go, and where answers come from.
+///
+/// By default this is the terminal (StdConsole). Install another console with
+/// set_console, e.g. one of the test consoles to run a game headlessly.
+pub trait Console {
+    /// Writes text as is, it may contain several or partial lines.
+    fn write(&mut self, text: &str);
+
+    /// Reads a line of input without the line ending, None when there is no more input.
+    ///
+    /// No more input quits the game, see the quit module.
+    fn read_line(&mut self) -> Option<String>;
+
+    /// Tells what kind of answer the next read_line is for.
+    ///
+    /// Only consoles that show it to their user (like a graphical frontend) need this.
+    fn hint(&mut self, _hint: &Hint) {}
+
+    /// Whether the console shows ANSI styling, false unless it's a terminal.
+    fn ansi(&self) -> bool {
+        false
+    }
+}
+
+/// The kind of answer a prompt expects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnswerKind {
+    #[default]
+    Text,
+    YesNo,
+    Number,
+    Float,
+    /// Several values on one line, with a separator between them.
+    List,
+}
+
+/// What a prompt expects as its answer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hint {
+    pub kind: AnswerKind,
+    /// Inclusive bounds of a number, or of each number of a list.
+    pub range: Option<(String, String)>,
+}
+
+impl Hint {
+    pub fn new(kind: AnswerKind) -> Self {
+        Hint { kind, range: None }
+    }
+
+    pub fn range<T: Debug>(mut self, range: &RangeInclusive<T>) -> Self {
+        self.range = Some((format!("{:?}", range.start()), format!("{:?}", range.end())));
+        self
+    }
+}
+
+/// The terminal: stdout and stdin.
+pub struct StdConsole;
+
+impl Console for StdConsole {
+    fn write(&mut self, text: &str) {
+        let mut stdout = io::stdout().lock();
+        stdout
+            .write_all(text.as_bytes())
+            .and_then(|_| stdout.flush())
+            .expect("Failed to write to stdout!");
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        let mut input = String::new();
+        match io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line!")
+        {
+            0 => None,
+            _ => Some(input.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+
+    fn ansi(&self) -> bool {
+        io::stdout().is_terminal()
+    }
+}
+
+/// Transforms or observes everything going in and out of the console.
+///
+/// Middleware runs in the order it was added, for both output and input.
+/// It must not use the console itself.
+pub trait Middleware {
+    /// Gets text about to be written, returns what gets written instead.
+    fn output(&mut self, text: String) -> String {
+        text
+    }
+
+    /// Gets a line that was read, returns what the game gets instead.
+    fn input(&mut self, line: String) -> String {
+        line
+    }
+}
+
+/// Uppercases all output, for games that should look like they run on a 1970s terminal.
+pub struct Uppercase;
+
+impl Middleware for Uppercase {
+    fn output(&mut self, text: String) -> String {
+        text.to_uppercase()
+    }
+}
+
+/// Waits before each write, to slow output down to the pace of a teletype.
+///
+/// Doesn't wait in accessibility mode, screen readers have their own pace.
+pub struct Delay(pub Duration);
+
+impl Middleware for Delay {
+    fn output(&mut self, text: String) -> String {
+        if !is_accessible() {
+            thread::sleep(self.0);
+        }
+        text
+    }
+}
+
+/// Appends everything written and read to a log file.
+pub struct FileLog {
+    file: File,
+}
+
+impl FileLog {
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(FileLog {
+            file: OpenOptions::new().create(true).append(true).open(path)?,
+        })
+    }
+}
+
+impl Middleware for FileLog {
+    fn output(&mut self, text: String) -> String {
+        let _ = self.file.write_all(text.as_bytes());
+        text
+    }
+
+    fn input(&mut self, line: String) -> String {
+        let _ = writeln!(self.file, "{line}");
+        line
+    }
+}
+
+thread_local! {
+    static CONSOLE: RefCell<Box<dyn Console>> = RefCell::new(Box::new(StdConsole));
+    static MIDDLEWARE: RefCell<Vec<Box<dyn Middleware>>> = RefCell::new(Vec::new());
+    static HELP: RefCell<Option<HelpSource>> = const { RefCell::new(None) };
+}
+
+pub(crate) type HelpSource = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Sets the help text shown when "HELP" or "?" is answered to a prompt, None turns it off.
+pub fn set_help(text: Option<&str>) {
+    let text = text.map(str::to_string);
+    HELP.with(|h| *h.borrow_mut() = text.map(|t| Arc::new(move || t.clone()) as HelpSource));
+}
+
+/// Sets a function rendering the help text each time it's asked for,
+/// e.g. so it follows the language chosen later.
+pub fn set_help_with<F: Fn() -> String + Send + Sync + 'static>(render: F) {
+    HELP.with(|h| *h.borrow_mut() = Some(Arc::new(render)));
+}
+
+pub(crate) fn help_source() -> Option<HelpSource> {
+    HELP.with(|h| h.borrow().clone())
+}
+
+pub(crate) fn set_help_source(source: Option<HelpSource>) {
+    HELP.with(|h| *h.borrow_mut() = source);
+}
+
+/// The registered help text.
+pub fn help() -> Option<String> {
+    let source = HELP.with(|h| h.borrow().clone());
+    source.map(|render| render())
+}
+
+/// Adds middleware to the console of the current thread.
+pub fn add_middleware(middleware: Box<dyn Middleware>) {
+    MIDDLEWARE.with(|m| m.borrow_mut().push(middleware));
+}
+
+/// Takes the middleware of the current thread out, leaving none.
+pub(crate) fn take_middleware() -> Vec<Box<dyn Middleware>> {
+    MIDDLEWARE.with(|m| std::mem::take(&mut *m.borrow_mut()))
+}
+
+pub(crate) fn set_middleware(middleware: Vec<Box<dyn Middleware>>) {
+    MIDDLEWARE.with(|m| *m.borrow_mut() = middleware);
+}
+
+/// Removes all middleware from the console of the current thread.
+pub fn clear_middleware() {
+    MIDDLEWARE.with(|m| m.borrow_mut().clear());
+}
+
+/// Replaces the console of the current thread, returning the previous one.
+pub fn set_console(console: Box<dyn Console>) -> Box<dyn Console> {
+    CONSOLE.with(|c| std::mem::replace(&mut *c.borrow_mut(), console))
+}
+
+/// Whether the console of the current thread shows ANSI styling.
+pub fn ansi() -> bool {
+    CONSOLE.with(|c| c.borrow().ansi())
+}
+
+/// Goes back to the terminal console for the current thread.
+pub fn reset_console() {
+    set_console(Box::new(StdConsole));
+}
+
+/// Writes text to the current console.
+pub fn write(text: &str) {
+    let text = MIDDLEWARE.with(|m| {
+        m.borrow_mut()
+            .iter_mut()
+            .fold(text.to_string(), |t, m| m.output(t))
+    });
+    CONSOLE.with(|c| c.borrow_mut().write(&text));
+}
+
+/// Writes a line to the current console.
+pub fn write_line(line: &str) {
+    write(&format!("{line}\n"));
+}
+
+/// Tells the current console what kind of answer the next read is for.
+pub fn hint(hint: &Hint) {
+    CONSOLE.with(|c| c.borrow_mut().hint(hint));
+}
+
+/// Reads a line from the current console.
+pub fn read_line() -> Option<String> {
+    let line = CONSOLE.with(|c| c.borrow_mut().read_line())?;
+    Some(MIDDLEWARE.with(|m| m.borrow_mut().iter_mut().fold(line, |l, m| m.input(l))))
+}
+
+/// Everything a test console has written and read, like it would look on a terminal.
+///
+/// A handle stays usable after the console was installed.
+#[derive(Clone, Default)]
+pub struct Transcript(Rc<RefCell<String>>);
+
+impl Transcript {
+    pub fn text(&self) -> String {
+        self.0.borrow().clone()
+    }
+
+    fn push(&self, text: &str) {
+        self.0.borrow_mut().push_str(text);
+    }
+}
+
+/// Test console answering prompts with a fixed sequence of answers.
+///
+/// Panics if the game asks for more answers than there are.
+pub struct ScriptedConsole {
+    answers: VecDeque<String>,
+    transcript: Transcript,
+}
+
+impl ScriptedConsole {
+    pub fn new<I, S>(answers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ScriptedConsole {
+            answers: answers.into_iter().map(Into::into).collect(),
+            transcript: Transcript::default(),
+        }
+    }
+
+    pub fn transcript(&self) -> Transcript {
+        self.transcript.clone()
+    }
+}
+
+impl Console for ScriptedConsole {
+    fn write(&mut self, text: &str) {
+        self.transcript.push(text);
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        let answer = match self.answers.pop_front() {
+            Some(a) => a,
+            None => panic!(
+                "no answers left, transcript so far:\n{}",
+                self.transcript.text()
+            ),
+        };
+        self.transcript.push(&format!("{answer}\n"));
+        Some(answer)
+    }
+}
+
+struct MappedAnswer {
+    key: String,
+    answers: VecDeque<String>,
+    always: bool,
+}
+
+/// Test console answering each prompt by what it says instead of by order.
+///
+/// When input is read, the text written since the last read is searched for the keys,
+/// in the order they were added, case insensitive, and the first match answers.
+/// That way tests keep working when prompts are reordered a little.
+///
+/// Panics if no key matches.
+#[derive(Default)]
+pub struct AnswerMapConsole {
+    answers: Vec<MappedAnswer>,
+    pending: String,
+    transcript: Transcript,
+}
+
+impl AnswerMapConsole {
+    pub fn new() -> Self {
+        AnswerMapConsole::default()
+    }
+
+    /// Answers a prompt containing "key" once. Adding the same key again queues more answers.
+    pub fn answer(mut self, key: &str, answer: &str) -> Self {
+        let key = key.to_uppercase();
+        match self.answers.iter_mut().find(|m| !m.always && m.key == key) {
+            Some(m) => m.answers.push_back(answer.to_string()),
+            None => self.answers.push(MappedAnswer {
+                key,
+                answers: VecDeque::from([answer.to_string()]),
+                always: false,
+            }),
+        }
+        self
+    }
+
+    /// Answers every prompt containing "key", after any answers queued with answer().
+    pub fn always(mut self, key: &str, answer: &str) -> Self {
+        self.answers.push(MappedAnswer {
+            key: key.to_uppercase(),
+            answers: VecDeque::from([answer.to_string()]),
+            always: true,
+        });
+        self
+    }
+
+    pub fn transcript(&self) -> Transcript {
+        self.transcript.clone()
+    }
+}
+
+impl Console for AnswerMapConsole {
+    fn write(&mut self, text: &str) {
+        self.pending.push_str(text);
+        self.transcript.push(text);
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        let prompt = std::mem::take(&mut self.pending).to_uppercase();
+        let matches = |m: &MappedAnswer| !m.answers.is_empty() && prompt.contains(&m.key);
+        let found = self
+            .answers
+            .iter()
+            .position(|m| !m.always && matches(m))
+            .or_else(|| self.answers.iter().position(|m| m.always && matches(m)));
+
+        let answer = match found {
+            Some(i) if self.answers[i].always => self.answers[i].answers[0].clone(),
+            Some(i) => self.answers[i].answers.pop_front().unwrap_or_default(),
+            None => panic!("no answer for prompt:\n{prompt}"),
+        };
+        self.transcript.push(&format!("{answer}\n"));
+        Some(answer)
+    }
+}
@@ -0,0 +1,90 @@
+use std::{collections::VecDeque, num::ParseIntError, str::FromStr};
+
+use crate::{
+    ask,
+    console::{AnswerKind, Hint},
+    lang::{message, Message},
+    read_line,
+};
+
+/// Undo/redo stack of game states.
+///
+/// Keeps at most "depth" states to go back to.
+pub struct History<T: Clone> {
+    past: VecDeque<T>,
+    current: T,
+    future: Vec<T>,
+    depth: usize,
+}
+
+impl<T: Clone> History<T> {
+    pub fn new(initial: T, depth: usize) -> Self {
+        History {
+            past: VecDeque::new(),
+            current: initial,
+            future: Vec::new(),
+            depth,
+        }
+    }
+
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Records a new state, which also forgets everything that could be redone.
+    pub fn push(&mut self, state: T) {
+        let previous = std::mem::replace(&mut self.current, state);
+        self.past.push_back(previous);
+        if self.past.len() > self.depth {
+            self.past.pop_front();
+        }
+        self.future.clear();
+    }
+
+    /// Goes back one state, None if there is nothing to undo.
+    pub fn undo(&mut self) -> Option<&T> {
+        let previous = self.past.pop_back()?;
+        let current = std::mem::replace(&mut self.current, previous);
+        self.future.push(current);
+        Some(&self.current)
+    }
+
+    /// Goes forward one undone state, None if there is nothing to redo.
+    pub fn redo(&mut self) -> Option<&T> {
+        let next = self.future.pop()?;
+        let current = std::mem::replace(&mut self.current, next);
+        self.past.push_back(current);
+        Some(&self.current)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+}
+
+/// Answer to a prompt that also accepts "UNDO" and "REDO".
+pub enum Undoable<T> {
+    Value(T),
+    Undo,
+    Redo,
+}
+
+/// Ask user for a number (of type T), or "UNDO"/"REDO".
+pub fn prompt_number_undoable<T: FromStr<Err = ParseIntError>>(msg: &str) -> Undoable<T> {
+    loop {
+        ask(msg, Hint::new(AnswerKind::Text));
+        let input = read_line();
+        match input.as_str() {
+            "UNDO" => return Undoable::Undo,
+            "REDO" => return Undoable::Redo,
+            _ => match input.parse::<T>() {
+                Ok(n) => return Undoable::Value(n),
+                Err(_) => say!("{}", message(Message::EnterValidNumberUndoRedo)),
+            },
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+static RAW_MODE: AtomicBool = AtomicBool::new(false);
+
+/// A single keypress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Backspace,
+    Tab,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Keeps the terminal in raw mode while alive, restores it on drop.
+///
+/// Game loops hold one for as long as they poll keys with poll_key.
+/// Keys are delivered as soon as they are pressed and are not echoed.
+/// Ctrl+C doesn't stop the program in raw mode, reading it gives an Interrupted error instead.
+pub struct RawMode {
+    restore: bool,
+}
+
+impl RawMode {
+    /// Switches the terminal to raw mode, fails if stdin isn't a terminal.
+    ///
+    /// Does nothing (and restores nothing) if raw mode is already on.
+    pub fn enable() -> io::Result<Self> {
+        if !io::stdin().is_terminal() {
+            return Err(io::Error::other("stdin is not a terminal"));
+        }
+        if RAW_MODE.swap(true, Ordering::SeqCst) {
+            return Ok(RawMode { restore: false });
+        }
+        if let Err(e) = terminal::enable_raw_mode() {
+            RAW_MODE.store(false, Ordering::SeqCst);
+            return Err(e);
+        }
+        Ok(RawMode { restore: true })
+    }
+
+    /// Waits for a single keypress, like the read_key function.
+    pub fn read_key(&self) -> io::Result<Key> {
+        loop {
+            if let Some(key) = decode_key(event::read()?)? {
+                return Ok(key);
+            }
+        }
+    }
+
+    /// Checks for a keypress, waiting at most "timeout" for one.
+    ///
+    /// Lets a game loop keep running while the user isn't pressing anything.
+    /// Keep the guard for the whole loop, so keys pressed between polls aren't echoed.
+    /// A zero timeout doesn't wait at all.
+    pub fn poll_key(&self, timeout: Duration) -> io::Result<Option<Key>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if !event::poll(left)? {
+                return Ok(None);
+            }
+            if let Some(key) = decode_key(event::read()?)? {
+                return Ok(Some(key));
+            }
+        }
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if self.restore {
+            let _ = terminal::disable_raw_mode();
+            RAW_MODE.store(false, Ordering::SeqCst);
+        }
+    }
+}
+
+/// The key of a key event, None for events and keys that aren't a Key.
+fn decode_key(event: Event) -> io::Result<Option<Key>> {
+    let Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        ..
+    }) = event
+    else {
+        return Ok(None);
+    };
+
+    let key = match code {
+        KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        KeyCode::Char(c) => Key::Char(c.to_uppercase().next().unwrap_or(c)),
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        _ => return Ok(None),
+    };
+    Ok(Some(key))
+}
+
+/// Waits for a single keypress, without the user having to hit enter.
+///
+/// Letters are returned uppercase, like the line prompts.
+/// Fails if stdin isn't a terminal.
+pub fn read_key() -> io::Result<Key> {
+    RawMode::enable()?.read_key()
+}
@@ -0,0 +1,448 @@
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+    iter::Peekable,
+    str::Chars,
+};
+
+use crate::{
+    console::{self, AnswerKind, Console, Hint},
+    quit,
+};
+
+/// A console speaking JSON-RPC 2.0, one message per line, for graphical or web frontends.
+///
+/// Each time the game reads input, everything written since the last read is sent as a request:
+///
+/// {"jsonrpc":"2.0","id":1,"method":"prompt","params":{"text":"HOW MANY?\n","expect":"number","range":[1,10]}}
+///
+/// and the frontend replies with the answer as "result" (a string, number or boolean):
+///
+/// {"jsonrpc":"2.0","id":1,"result":5}
+///
+/// Output left when the console is dropped is sent as an "output" notification.
+pub struct JsonRpcConsole {
+    input: Box<dyn BufRead>,
+    output: Box<dyn Write>,
+    next_id: u64,
+    hint: Hint,
+    pending: String,
+}
+
+impl JsonRpcConsole {
+    /// Speaks JSON-RPC over stdin and stdout.
+    pub fn new() -> Self {
+        JsonRpcConsole::with_io(io::BufReader::new(io::stdin()), io::stdout())
+    }
+
+    pub fn with_io<R, W>(input: R, output: W) -> Self
+    where
+        R: BufRead + 'static,
+        W: Write + 'static,
+    {
+        JsonRpcConsole {
+            input: Box::new(input),
+            output: Box::new(output),
+            next_id: 1,
+            hint: Hint::default(),
+            pending: String::new(),
+        }
+    }
+
+    fn send(&mut self, message: &str) {
+        writeln!(self.output, "{message}")
+            .and_then(|_| self.output.flush())
+            .expect("Failed to send JSON-RPC message!");
+    }
+
+    fn send_error(&mut self, id: Option<&Json>, message: &str) {
+        let id = match id {
+            Some(Json::Number(n)) => n.clone(),
+            Some(Json::Str(s)) => quote(s),
+            _ => "null".to_string(),
+        };
+        self.send(&format!(
+            r#"{{"jsonrpc":"2.0","id":{id},"error":{{"code":-32600,"message":{}}}}}"#,
+            quote(message)
+        ));
+    }
+}
+
+impl Default for JsonRpcConsole {
+    fn default() -> Self {
+        JsonRpcConsole::new()
+    }
+}
+
+impl Drop for JsonRpcConsole {
+    fn drop(&mut self) {
+        if !self.pending.is_empty() {
+            let text = quote(&std::mem::take(&mut self.pending));
+            let _ = writeln!(
+                self.output,
+                r#"{{"jsonrpc":"2.0","method":"output","params":{{"text":{text}}}}}"#
+            );
+            let _ = self.output.flush();
+        }
+    }
+}
+
+impl Console for JsonRpcConsole {
+    fn write(&mut self, text: &str) {
+        self.pending.push_str(text);
+    }
+
+    fn hint(&mut self, hint: &Hint) {
+        self.hint = hint.clone();
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let hint = std::mem::take(&mut self.hint);
+        let mut params = format!(
+            r#"{{"text":{},"expect":"{}""#,
+            quote(&std::mem::take(&mut self.pending)),
+            kind_name(hint.kind)
+        );
+        if let Some((start, end)) = &hint.range {
+            let _ = write!(params, r#","range":[{},{}]"#, number(start), number(end));
+        }
+        params.push('}');
+        self.send(&format!(
+            r#"{{"jsonrpc":"2.0","id":{id},"method":"prompt","params":{params}}}"#
+        ));
+
+        loop {
+            let mut line = String::new();
+            if self
+                .input
+                .read_line(&mut line)
+                .expect("Failed to read line!")
+                == 0
+            {
+                return None;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some(Json::Object(fields)) = parse(&line) else {
+                self.send_error(None, "expected a JSON object");
+                continue;
+            };
+            let get = |key: &str| fields.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+
+            if !matches!(get("id"), Some(Json::Number(n)) if *n == id.to_string()) {
+                self.send_error(get("id"), &format!("expected the answer to prompt {id}"));
+                continue;
+            }
+            match get("result") {
+                Some(Json::Str(s)) => return Some(s.clone()),
+                Some(Json::Number(n)) => return Some(n.clone()),
+                Some(Json::Bool(true)) => return Some("YES".to_string()),
+                Some(Json::Bool(false)) => return Some("NO".to_string()),
+                _ => self.send_error(get("id"), "result must be a string, number or boolean"),
+            }
+        }
+    }
+}
+
+/// Runs a game with a JSON-RPC frontend on stdin and stdout.
+pub fn run_jsonrpc<F: FnOnce()>(game: F) {
+    let previous = console::set_console(Box::new(JsonRpcConsole::new()));
+    quit::run_game(game);
+    // dropping the JSON-RPC console sends the rest of the output
+    drop(console::set_console(previous));
+}
+
+fn kind_name(kind: AnswerKind) -> &'static str {
+    match kind {
+        AnswerKind::Text => "text",
+        AnswerKind::YesNo => "yes_no",
+        AnswerKind::Number => "number",
+        AnswerKind::Float => "float",
+        AnswerKind::List => "list",
+    }
+}
+
+/// A range bound as a JSON number, or a string if it isn't one.
+fn number(bound: &str) -> String {
+    match bound.parse::<f64>() {
+        Ok(n) if n.is_finite() => bound.to_string(),
+        _ => quote(bound),
+    }
+}
+
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Just enough JSON to read answers.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    /// Kept as written.
+    Number(String),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn parse(text: &str) -> Option<Json> {
+    let mut chars = text.chars().peekable();
+    let value = parse_value(&mut chars)?;
+    skip_whitespace(&mut chars);
+    chars.peek().is_none().then_some(value)
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Option<Json> {
+    skip_whitespace(chars);
+    match *chars.peek()? {
+        '{' => {
+            chars.next();
+            let mut fields = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Some(Json::Object(fields));
+            }
+            loop {
+                skip_whitespace(chars);
+                let Json::Str(key) = parse_value(chars)? else {
+                    return None;
+                };
+                skip_whitespace(chars);
+                chars.next_if_eq(&':')?;
+                fields.push((key, parse_value(chars)?));
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    '}' => return Some(Json::Object(fields)),
+                    _ => return None,
+                }
+            }
+        }
+        '[' => {
+            chars.next();
+            let mut items = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Some(Json::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    ']' => return Some(Json::Array(items)),
+                    _ => return None,
+                }
+            }
+        }
+        '"' => {
+            chars.next();
+            parse_string(chars).map(Json::Str)
+        }
+        't' => parse_word(chars, "true", Json::Bool(true)),
+        'f' => parse_word(chars, "false", Json::Bool(false)),
+        'n' => parse_word(chars, "null", Json::Null),
+        _ => {
+            let mut n = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || "+-.eE".contains(*c)) {
+                n.push(c);
+            }
+            n.parse::<f64>().ok().map(|_| Json::Number(n))
+        }
+    }
+}
+
+fn parse_word(chars: &mut Peekable<Chars>, word: &str, value: Json) -> Option<Json> {
+    for expected in word.chars() {
+        chars.next_if_eq(&expected)?;
+    }
+    Some(value)
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => match chars.next()? {
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                'b' => s.push('\u{8}'),
+                'f' => s.push('\u{c}'),
+                'u' => {
+                    let mut code = parse_hex(chars)?;
+                    if (0xD800..0xDC00).contains(&code) {
+                        // surrogate pair
+                        chars.next_if_eq(&'\\')?;
+                        chars.next_if_eq(&'u')?;
+                        let low = parse_hex(chars)?;
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return None;
+                        }
+                        code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                    }
+                    s.push(char::from_u32(code)?);
+                }
+                c => s.push(c),
+            },
+            c => s.push(c),
+        }
+    }
+}
+
+fn parse_hex(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let hex: String = (0..4).filter_map(|_| chars.next()).collect();
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, io::Cursor, rc::Rc};
+
+    use super::*;
+
+    /// Output that stays readable after the console took it.
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Shared {
+        fn lines(&self) -> Vec<String> {
+            String::from_utf8(self.0.borrow().clone())
+                .unwrap()
+                .lines()
+                .map(str::to_string)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn parses_escapes() {
+        assert_eq!(
+            parse(r#""a\"b\\c\/d\n\t\u00e9""#),
+            Some(Json::Str("a\"b\\c/d\n\té".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_surrogate_pairs() {
+        assert_eq!(
+            parse(r#""\ud83d\ude00""#),
+            Some(Json::Str("\u{1F600}".to_string()))
+        );
+        assert_eq!(parse(r#""\ud83d""#), None);
+        assert_eq!(parse(r#""\ud83d\u0041""#), None);
+        assert_eq!(parse(r#""\ud83d\ue000""#), None);
+    }
+
+    #[test]
+    fn parses_objects() {
+        assert_eq!(
+            parse(r#" {"id": 1, "result": [true, null, -2.5e3]} "#),
+            Some(Json::Object(vec![
+                ("id".to_string(), Json::Number("1".to_string())),
+                (
+                    "result".to_string(),
+                    Json::Array(vec![
+                        Json::Bool(true),
+                        Json::Null,
+                        Json::Number("-2.5e3".to_string())
+                    ])
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        for text in [
+            "",
+            "{",
+            r#"{"id":1,}"#,
+            r#"{"id" 1}"#,
+            r#"{1:2}"#,
+            "[1 2]",
+            r#""open"#,
+            "tru",
+            "--",
+            r#"{"id":1} x"#,
+            r#""\uzzzz""#,
+        ] {
+            assert_eq!(parse(text), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn quotes_control_characters() {
+        assert_eq!(quote("a\"\\\n\u{1}"), r#""a\"\\\n\u0001""#);
+    }
+
+    #[test]
+    fn rejects_answers_to_other_prompts() {
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","id":2,"result":"LATE"}"#,
+            "\n",
+            "not json\n",
+            r#"{"jsonrpc":"2.0","id":1,"result":[1]}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","id":1,"result":5}"#,
+            "\n",
+        );
+        let output = Shared::default();
+        let mut console = JsonRpcConsole::with_io(Cursor::new(input), output.clone());
+        console.write("HOW MANY?\n");
+        console.hint(&Hint::new(AnswerKind::Number));
+
+        assert_eq!(console.read_line(), Some("5".to_string()));
+        assert_eq!(console.read_line(), None);
+
+        let lines = output.lines();
+        assert_eq!(
+            lines[0],
+            r#"{"jsonrpc":"2.0","id":1,"method":"prompt","params":{"text":"HOW MANY?\n","expect":"number"}}"#
+        );
+        assert!(lines[1].contains(r#""id":2,"error""#), "{}", lines[1]);
+        assert!(lines[1].contains("expected the answer to prompt 1"));
+        assert!(lines[2].contains(r#""id":null,"error""#), "{}", lines[2]);
+        assert!(lines[3].contains(r#""id":1,"error""#), "{}", lines[3]);
+        assert!(lines[4].contains(r#""method":"prompt""#));
+    }
+}
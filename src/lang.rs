@@ -0,0 +1,372 @@
+use std::{
+    cell::Cell,
+    fmt::Display,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::prompt_number_range;
+
+static LANG: AtomicU8 = AtomicU8::new(Lang::English as u8);
+
+thread_local! {
+    static PINNED: Cell<Option<Lang>> = const { Cell::new(None) };
+}
+
+/// A language games and built-in messages can be shown in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Lang {
+    #[default]
+    English,
+    Spanish,
+    French,
+    German,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 4] = [Lang::English, Lang::Spanish, Lang::French, Lang::German];
+
+    /// The language's name in the language itself, e.g. "ESPAÑOL".
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::English => "ENGLISH",
+            Lang::Spanish => "ESPAÑOL",
+            Lang::French => "FRANÇAIS",
+            Lang::German => "DEUTSCH",
+        }
+    }
+}
+
+/// Sets the language of built-in messages and localized instructions.
+pub fn set_lang(lang: Lang) {
+    if PINNED.get().is_some() {
+        PINNED.set(Some(lang));
+    } else {
+        LANG.store(lang as u8, Ordering::Relaxed);
+    }
+}
+
+pub fn lang() -> Lang {
+    PINNED
+        .get()
+        .unwrap_or(Lang::ALL[LANG.load(Ordering::Relaxed) as usize])
+}
+
+/// Gives the current thread its own language, so set_lang there doesn't change other threads.
+///
+/// None goes back to the shared language. Returns the previous pin.
+pub(crate) fn pin_lang(lang: Option<Lang>) -> Option<Lang> {
+    PINNED.replace(lang)
+}
+
+/// Lists the languages, asks for one and switches to it.
+pub fn prompt_language() -> Lang {
+    for (i, lang) in Lang::ALL.iter().enumerate() {
+        say!("{}. {}", i + 1, lang.name());
+    }
+    let n = prompt_number_range(message(Message::ChooseLanguage), 1..=Lang::ALL.len());
+    let lang = Lang::ALL[n - 1];
+    set_lang(lang);
+    lang
+}
+
+/// The built-in messages of the crate, see message and message_with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Message {
+    AnswerTooLong,
+    AutumnSeason,
+    AverageScore,
+    BestScore,
+    CalendarDate,
+    ChooseLanguage,
+    EnterAnswer,
+    EnterEvenNumber,
+    EnterMultipleOf,
+    EnterName,
+    EnterNumberOfUnits,
+    EnterOneOrZero,
+    EnterOneYesZeroNo,
+    EnterValidAnswerOr,
+    EnterValidNumber,
+    EnterValidNumberUndoRedo,
+    EnterYesOrNo,
+    FinalScore,
+    GamesPlayed,
+    HowManyPlayers,
+    LifetimeStats,
+    No,
+    NoGamesPlayed,
+    NotANumber,
+    NotEnough,
+    NotWithin,
+    Now,
+    NumberWithin,
+    Or,
+    Player,
+    PlayerName,
+    ResumeGame,
+    SpringSeason,
+    SummerSeason,
+    TimePlayed,
+    TooMany,
+    TooSoon,
+    Total,
+    UnitsExactly,
+    UnitsWithin,
+    WinterSeason,
+    YearOfReign,
+    Yes,
+    YourTurn,
+}
+
+/// The message in the current language.
+///
+/// Messages with "{}" placeholders are filled in by message_with.
+pub fn message(m: Message) -> &'static str {
+    use Message::*;
+
+    // English, Spanish, French, German
+    let texts = match m {
+        AnswerTooLong => [
+            "ANSWER MUST BE AT MOST {} CHARACTERS",
+            "LA RESPUESTA DEBE TENER COMO MÁXIMO {} CARACTERES",
+            "LA RÉPONSE DOIT FAIRE AU PLUS {} CARACTÈRES",
+            "DIE ANTWORT DARF HÖCHSTENS {} ZEICHEN LANG SEIN",
+        ],
+        AutumnSeason => ["AUTUMN", "OTOÑO", "AUTOMNE", "HERBST"],
+        AverageScore => [
+            "AVERAGE SCORE: {}",
+            "PUNTUACIÓN MEDIA: {}",
+            "SCORE MOYEN : {}",
+            "DURCHSCHNITTLICHE PUNKTZAHL: {}",
+        ],
+        BestScore => [
+            "BEST SCORE: {}",
+            "MEJOR PUNTUACIÓN: {}",
+            "MEILLEUR SCORE : {}",
+            "BESTE PUNKTZAHL: {}",
+        ],
+        CalendarDate => [
+            "DAY {} OF {}, YEAR {}",
+            "DÍA {} DE {}, AÑO {}",
+            "JOUR {}, {}, ANNÉE {}",
+            "TAG {} IM {}, JAHR {}",
+        ],
+        ChooseLanguage => [
+            "CHOOSE A LANGUAGE",
+            "ELIGE UN IDIOMA",
+            "CHOISISSEZ UNE LANGUE",
+            "WÄHLE EINE SPRACHE",
+        ],
+        EnterAnswer => [
+            "PLEASE ENTER AN ANSWER",
+            "POR FAVOR, ESCRIBE UNA RESPUESTA",
+            "VEUILLEZ ENTRER UNE RÉPONSE",
+            "BITTE GIB EINE ANTWORT EIN",
+        ],
+        EnterEvenNumber => [
+            "ENTER AN EVEN NUMBER",
+            "ESCRIBE UN NÚMERO PAR",
+            "ENTREZ UN NOMBRE PAIR",
+            "GIB EINE GERADE ZAHL EIN",
+        ],
+        EnterMultipleOf => [
+            "ENTER A MULTIPLE OF {}",
+            "ESCRIBE UN MÚLTIPLO DE {}",
+            "ENTREZ UN MULTIPLE DE {}",
+            "GIB EIN VIELFACHES VON {} EIN",
+        ],
+        EnterName => [
+            "PLEASE ENTER A NAME",
+            "POR FAVOR, ESCRIBE UN NOMBRE",
+            "VEUILLEZ ENTRER UN NOM",
+            "BITTE GIB EINEN NAMEN EIN",
+        ],
+        EnterNumberOfUnits => [
+            "ENTER A NUMBER OF {}",
+            "ESCRIBE UN NÚMERO DE {}",
+            "ENTREZ UN NOMBRE DE {}",
+            "GIB EINE ANZAHL {} EIN",
+        ],
+        EnterOneOrZero => [
+            "ENTER A NUMBER (1 OR 0)",
+            "ESCRIBE UN NÚMERO (1 O 0)",
+            "ENTREZ UN NOMBRE (1 OU 0)",
+            "GIB EINE ZAHL EIN (1 ODER 0)",
+        ],
+        EnterOneYesZeroNo => [
+            "ENTER 1 (YES) OR 0 (NO)",
+            "ESCRIBE 1 (SÍ) O 0 (NO)",
+            "ENTREZ 1 (OUI) OU 0 (NON)",
+            "GIB 1 (JA) ODER 0 (NEIN) EIN",
+        ],
+        EnterValidAnswerOr => [
+            "ENTER A VALID ANSWER OR {}",
+            "ESCRIBE UNA RESPUESTA VÁLIDA O {}",
+            "ENTREZ UNE RÉPONSE VALIDE OU {}",
+            "GIB EINE GÜLTIGE ANTWORT ODER {} EIN",
+        ],
+        EnterValidNumber => [
+            "ENTER A VALID NUMBER",
+            "ESCRIBE UN NÚMERO VÁLIDO",
+            "ENTREZ UN NOMBRE VALIDE",
+            "GIB EINE GÜLTIGE ZAHL EIN",
+        ],
+        EnterValidNumberUndoRedo => [
+            "ENTER A VALID NUMBER, UNDO OR REDO",
+            "ESCRIBE UN NÚMERO VÁLIDO, UNDO O REDO",
+            "ENTREZ UN NOMBRE VALIDE, UNDO OU REDO",
+            "GIB EINE GÜLTIGE ZAHL, UNDO ODER REDO EIN",
+        ],
+        EnterYesOrNo => [
+            "ENTER (Y)ES OR (N)O",
+            "ESCRIBE (S)Í O (N)O",
+            "ENTREZ (O)UI OU (N)ON",
+            "GIB (J)A ODER (N)EIN EIN",
+        ],
+        FinalScore => ["FINAL SCORE", "PUNTUACIÓN FINAL", "SCORE FINAL", "ENDSTAND"],
+        GamesPlayed => [
+            "GAMES PLAYED: {}",
+            "PARTIDAS JUGADAS: {}",
+            "PARTIES JOUÉES : {}",
+            "GESPIELTE SPIELE: {}",
+        ],
+        HowManyPlayers => [
+            "HOW MANY PLAYERS?",
+            "¿CUÁNTOS JUGADORES?",
+            "COMBIEN DE JOUEURS ?",
+            "WIE VIELE SPIELER?",
+        ],
+        LifetimeStats => [
+            "LIFETIME STATS FOR {}",
+            "ESTADÍSTICAS DE {}",
+            "STATISTIQUES DE {}",
+            "STATISTIK FÜR {}",
+        ],
+        No => ["NO", "NO", "NON", "NEIN"],
+        NoGamesPlayed => [
+            "NO GAMES PLAYED YET",
+            "TODAVÍA NO SE HA JUGADO NINGUNA PARTIDA",
+            "AUCUNE PARTIE JOUÉE POUR L'INSTANT",
+            "NOCH KEINE SPIELE GESPIELT",
+        ],
+        NotANumber => [
+            "'{}' IS NOT A NUMBER (ITEM {} OF {})",
+            "'{}' NO ES UN NÚMERO (ELEMENTO {} DE {})",
+            "'{}' N'EST PAS UN NOMBRE (ÉLÉMENT {} SUR {})",
+            "'{}' IST KEINE ZAHL (ELEMENT {} VON {})",
+        ],
+        NotEnough => [
+            "THINK AGAIN. YOU HAVE ONLY {} {}.",
+            "PIÉNSALO OTRA VEZ. SOLO TIENES {} {}.",
+            "RÉFLÉCHISSEZ. VOUS N'AVEZ QUE {} {}.",
+            "ÜBERLEG NOCH MAL. DU HAST NUR {} {}.",
+        ],
+        NotWithin => [
+            "'{}' IS NOT WITHIN {} AND {} (ITEM {} OF {})",
+            "'{}' NO ESTÁ ENTRE {} Y {} (ELEMENTO {} DE {})",
+            "'{}' N'EST PAS ENTRE {} ET {} (ÉLÉMENT {} SUR {})",
+            "'{}' LIEGT NICHT ZWISCHEN {} UND {} (ELEMENT {} VON {})",
+        ],
+        Now => ["NOW!", "¡YA!", "MAINTENANT !", "JETZT!"],
+        NumberWithin => [
+            "ENTER A NUMBER WITHIN {}, AND {}",
+            "ESCRIBE UN NÚMERO ENTRE {} Y {}",
+            "ENTREZ UN NOMBRE ENTRE {} ET {}",
+            "GIB EINE ZAHL ZWISCHEN {} UND {} EIN",
+        ],
+        Or => ["OR", "O", "OU", "ODER"],
+        Player => ["PLAYER {}", "JUGADOR {}", "JOUEUR {}", "SPIELER {}"],
+        PlayerName => [
+            "PLAYER {}, WHAT IS YOUR NAME?",
+            "JUGADOR {}, ¿CÓMO TE LLAMAS?",
+            "JOUEUR {}, QUEL EST VOTRE NOM ?",
+            "SPIELER {}, WIE HEISST DU?",
+        ],
+        ResumeGame => [
+            "RESUME PREVIOUS GAME?",
+            "¿CONTINUAR LA PARTIDA ANTERIOR?",
+            "REPRENDRE LA PARTIE PRÉCÉDENTE ?",
+            "LETZTES SPIEL FORTSETZEN?",
+        ],
+        SpringSeason => ["SPRING", "PRIMAVERA", "PRINTEMPS", "FRÜHLING"],
+        SummerSeason => ["SUMMER", "VERANO", "ÉTÉ", "SOMMER"],
+        TimePlayed => [
+            "TIME PLAYED: {} HOURS {} MINUTES {} SECONDS",
+            "TIEMPO JUGADO: {} HORAS {} MINUTOS {} SEGUNDOS",
+            "TEMPS DE JEU : {} HEURES {} MINUTES {} SECONDES",
+            "SPIELZEIT: {} STUNDEN {} MINUTEN {} SEKUNDEN",
+        ],
+        TooMany => [
+            "THAT'S MORE {} THAN ANYONE COULD KEEP.",
+            "NADIE PODRÍA GUARDAR TANTO {}.",
+            "PERSONNE NE PEUT GARDER AUTANT DE {}.",
+            "SO VIEL {} KANN NIEMAND BEHALTEN.",
+        ],
+        TooSoon => [
+            "TOO SOON! WAIT FOR THE SIGNAL.",
+            "¡DEMASIADO PRONTO! ESPERA LA SEÑAL.",
+            "TROP TÔT ! ATTENDEZ LE SIGNAL.",
+            "ZU FRÜH! WARTE AUF DAS SIGNAL.",
+        ],
+        Total => ["TOTAL", "TOTAL", "TOTAL", "GESAMT"],
+        UnitsExactly => [
+            "THERE MUST BE {} UNITS",
+            "DEBE HABER {} ELEMENTOS",
+            "IL FAUT {} ÉLÉMENTS",
+            "ES MÜSSEN {} ELEMENTE SEIN",
+        ],
+        UnitsWithin => [
+            "AMOUNT OF UNITS MUST BE WITHIN {} AND {}",
+            "EL NÚMERO DE ELEMENTOS DEBE ESTAR ENTRE {} Y {}",
+            "LE NOMBRE D'ÉLÉMENTS DOIT ÊTRE ENTRE {} ET {}",
+            "DIE ANZAHL DER ELEMENTE MUSS ZWISCHEN {} UND {} LIEGEN",
+        ],
+        WinterSeason => ["WINTER", "INVIERNO", "HIVER", "WINTER"],
+        YearOfReign => [
+            "IN THE YEAR {} OF YOUR REIGN",
+            "EN EL AÑO {} DE TU REINADO",
+            "EN L'AN {} DE VOTRE RÈGNE",
+            "IM JAHR {} DEINER HERRSCHAFT",
+        ],
+        Yes => ["YES", "SÍ", "OUI", "JA"],
+        YourTurn => [
+            "{}, IT'S YOUR TURN",
+            "{}, TE TOCA",
+            "{}, C'EST VOTRE TOUR",
+            "{}, DU BIST DRAN",
+        ],
+    };
+    texts[lang() as usize]
+}
+
+/// The message in the current language, with its "{}" placeholders replaced by "args" in order.
+pub fn message_with(m: Message, args: &[&dyn Display]) -> String {
+    let mut parts = message(m).split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    for (i, part) in parts.enumerate() {
+        if let Some(arg) = args.get(i) {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+/// Whether the answer means yes, in English or the current language (or its first letter).
+pub fn is_yes(answer: &str) -> bool {
+    matches_word(answer, Message::Yes)
+}
+
+/// Whether the answer means no, in English or the current language (or its first letter).
+pub fn is_no(answer: &str) -> bool {
+    matches_word(answer, Message::No)
+}
+
+fn matches_word(answer: &str, word: Message) -> bool {
+    let english = if word == Message::Yes { "YES" } else { "NO" };
+    // "SI" for "SÍ"
+    let answer = answer.to_uppercase().replace('Í', "I");
+    [english, &message(word).replace('Í', "I")]
+        .iter()
+        .any(|w| answer == *w || (answer.chars().count() == 1 && w.starts_with(&answer)))
+}
@@ -1,23 +1,72 @@
+/// println! through the current console.
+macro_rules! say {
+    () => {
+        $crate::console::write_line("")
+    };
+    ($($arg:tt)*) => {
+        $crate::console::write_line(&format!($($arg)*))
+    };
+}
+
+pub mod accessibility;
+pub mod ai;
+pub mod audio;
+pub mod banner;
+pub mod channel;
+#[cfg(feature = "chat")]
+pub mod chat;
+pub mod console;
+pub mod history;
+#[cfg(feature = "input")]
+pub mod input;
+pub mod jsonrpc;
+pub mod lang;
+pub mod markup;
+pub mod players;
+pub mod progress;
+pub mod quit;
+pub mod rng;
+pub mod save;
+pub mod score;
+pub mod screen;
+pub mod sim;
+pub mod stats;
+pub mod testing;
+pub mod words;
+
 use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
     fmt::{Debug, Display},
-    io,
     num::ParseIntError,
-    ops::RangeInclusive,
+    ops::{RangeInclusive, Rem},
     str::FromStr,
+    thread,
+    time::{Duration, Instant},
 };
 
+use console::{AnswerKind, Hint};
+use lang::{message, message_with, Lang, Message};
+use players::PlayerName;
+
 /// Options for displaying game instructions in the intro.
 ///
 /// Determine if we want to ask to show instructions
 /// and whether that prompt expects a 1 or 0.
 ///
 /// You can also create a multiline (Vec<&str>) instruction set.
+///
+/// With with_markup the text may use the lightweight markup of the markup module:
+/// "*bold*", "_underline_" and "{center}" at the start of a line.
+/// Otherwise it's printed exactly as given.
 pub struct Instructions<'a> {
     ask_numeric: (bool, bool),
     msg: &'a str,
     instructions: &'a str,
     instructions_multiline: Vec<&'a str>,
     multiline: bool,
+    localized: HashMap<Lang, Vec<&'a str>>,
+    markup: bool,
 }
 
 impl<'a> Instructions<'a> {
@@ -28,6 +77,8 @@ impl<'a> Instructions<'a> {
             instructions,
             instructions_multiline: Vec::new(),
             multiline: false,
+            localized: HashMap::new(),
+            markup: false,
         }
     }
 
@@ -43,35 +94,169 @@ impl<'a> Instructions<'a> {
             instructions: "",
             instructions_multiline: instructions,
             multiline: true,
+            localized: HashMap::new(),
+            markup: false,
         }
     }
 
+    /// Adds translations of the instructions, shown instead when their language is chosen
+    /// (see lang::prompt_language).
+    ///
+    /// Languages without a translation get the instructions given to new or new_multiline.
+    pub fn localized(mut self, map: HashMap<Lang, Vec<&'a str>>) -> Self {
+        self.localized = map;
+        self
+    }
+
+    /// Renders the markup of the markup module in the instruction text.
+    pub fn with_markup(mut self) -> Self {
+        self.markup = true;
+        self
+    }
+
     pub fn print(&self) {
-        if self.ask_numeric.0 {
-            if prompt_bool(self.msg, self.ask_numeric.1) {
-                if self.multiline {
-                    for l in self.instructions_multiline.iter() {
-                        println!("{}", l);
-                    }
-                } else {
-                    println!("{}", self.instructions);
-                }
-            }
+        if self.ask_numeric.0 && prompt_bool(self.msg, self.ask_numeric.1) {
+            say!("{}", self.render_styled(markup::ansi_supported()));
+        }
+    }
+
+    /// Registers the instructions as help, shown when "HELP" or "?" is answered to any prompt.
+    ///
+    /// They're rendered when asked for, in the language current at that time.
+    pub fn register_help(&self) {
+        let default = self.default_text();
+        let localized: HashMap<Lang, String> = self
+            .localized
+            .iter()
+            .map(|(lang, lines)| (*lang, lines.join("\n")))
+            .collect();
+        let with_markup = self.markup;
+        console::set_help_with(move || {
+            let text = localized.get(&lang::lang()).unwrap_or(&default);
+            style_instructions(text, with_markup, markup::ansi_supported())
+        });
+    }
+
+    /// The instruction text, with markup stripped if with_markup is used.
+    pub fn render(&self) -> String {
+        self.render_styled(false)
+    }
+
+    fn render_styled(&self, ansi: bool) -> String {
+        let text = match self.localized.get(&lang::lang()) {
+            Some(lines) => lines.join("\n"),
+            None => self.default_text(),
+        };
+        style_instructions(&text, self.markup, ansi)
+    }
+
+    fn default_text(&self) -> String {
+        if self.multiline {
+            self.instructions_multiline.join("\n")
+        } else {
+            self.instructions.to_string()
         }
     }
 }
 
+fn style_instructions(text: &str, with_markup: bool, ansi: bool) -> String {
+    if !with_markup {
+        return text.to_string();
+    }
+    text.lines()
+        .map(|l| markup::render_markup(l, ansi))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Provenance of a ported game.
+///
+/// Implement this once per game so the intro and attribution stay consistent.
+pub trait GameMeta {
+    fn title(&self) -> &str;
+    fn author(&self) -> &str;
+    fn year(&self) -> u16;
+
+    /// Where the original listing was published.
+    fn source(&self) -> &str {
+        "BASIC COMPUTER GAMES"
+    }
+}
+
 /// Prints the game intro template.
 pub fn print_intro(name: &str) {
-    println!("\n\n\t\t{name}\nCREATIVE COMPUTING MORRISTOWN, NEW JERSEY\n");
+    say!("{}", render_intro(name));
+}
+
+/// The game intro template as text.
+pub fn render_intro(name: &str) -> String {
+    format!("\n\n\t\t{name}\nCREATIVE COMPUTING MORRISTOWN, NEW JERSEY\n")
+}
+
+/// Prints the game intro template from the game's metadata.
+///
+/// Set parameter "attribution" to true to also print who wrote the original and where it appeared.
+pub fn print_game_intro<M: GameMeta>(meta: &M, attribution: bool) {
+    say!("{}", render_game_intro(meta, attribution));
+}
+
+/// The game intro template from the game's metadata as text.
+pub fn render_game_intro<M: GameMeta>(meta: &M, attribution: bool) -> String {
+    let mut intro = render_intro(meta.title());
+    if attribution {
+        intro.push_str(&format!(
+            "\nORIGINAL GAME BY {}, {}\nFROM {}\n",
+            meta.author(),
+            meta.year(),
+            meta.source()
+        ));
+    }
+    intro
 }
 
 fn read_line() -> String {
-    let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .expect("Failed to read line!");
-    input.trim().to_uppercase()
+    read_raw_line().to_uppercase()
+}
+
+thread_local! {
+    /// Answers read by prompts on this thread, for counting attempts.
+    static ANSWERS_READ: Cell<u32> = const { Cell::new(0) };
+    /// The question being asked, to ask again after HELP.
+    static QUESTION: RefCell<(String, Hint)> = RefCell::default();
+}
+
+/// Asks the question "msg", telling the console what kind of answer it wants.
+fn ask(msg: &str, hint: Hint) {
+    say!("{}", msg);
+    console::hint(&hint);
+    QUESTION.with(|q| *q.borrow_mut() = (msg.to_string(), hint));
+}
+
+/// Reads a line as typed, only trimmed.
+///
+/// If help is registered, "HELP" or "?" shows it and asks the question again.
+/// The quit word (if set) and the end of input (e.g. a frontend that went away) quit the game.
+fn read_raw_line() -> String {
+    loop {
+        let Some(line) = console::read_line() else {
+            quit::quit();
+        };
+        let line = line.trim().to_string();
+        if quit::is_quit_word(&line) {
+            quit::quit();
+        }
+        match console::help() {
+            Some(help) if line == "?" || line.eq_ignore_ascii_case("HELP") => {
+                say!("{}", help);
+                let (msg, hint) = QUESTION.with(|q| q.borrow().clone());
+                ask(&msg, hint);
+            }
+            _ => {
+                ANSWERS_READ.with(|n| n.set(n.get().wrapping_add(1)));
+                return line;
+            }
+        }
+    }
 }
 
 fn read_number<T: FromStr<Err = ParseIntError>>() -> Result<T, ParseIntError> {
@@ -79,68 +264,337 @@ fn read_number<T: FromStr<Err = ParseIntError>>() -> Result<T, ParseIntError> {
     input.parse::<T>()
 }
 
+fn read_float() -> Option<f64> {
+    let n = read_line().parse::<f64>().ok()?;
+    n.is_finite().then_some(n)
+}
+
 /// Asks user for a simple string.
 pub fn prompt_string(msg: &str) -> String {
-    println!("{}", msg);
+    ask(msg, Hint::new(AnswerKind::Text));
     read_line()
 }
 
+/// Asks user for a string, asking again if the answer is blank.
+pub fn prompt_string_nonempty(msg: &str) -> String {
+    prompt_string_limited(msg, None)
+}
+
+/// Asks user for a string that isn't blank, optionally no longer than "max_len" characters.
+pub fn prompt_string_limited(msg: &str, max_len: Option<usize>) -> String {
+    loop {
+        let answer = prompt_string(msg);
+        match max_len {
+            _ if answer.is_empty() => say!("{}", message(Message::EnterAnswer)),
+            Some(max) if answer.chars().count() > max => {
+                say!("{}", message_with(Message::AnswerTooLong, &[&max]))
+            }
+            _ => return answer,
+        }
+    }
+}
+
+/// Asks user for their name, keeping it as typed ("Alice") and asking again if it's blank.
+pub fn prompt_name(msg: &str) -> PlayerName {
+    loop {
+        ask(msg, Hint::new(AnswerKind::Text));
+        match PlayerName::new(&read_raw_line()) {
+            Some(name) => return name,
+            None => say!("{}", message(Message::EnterName)),
+        }
+    }
+}
+
+/// Asks user for several lines of text, until a blank line is entered.
+///
+/// You can also optionally set a word (like "END") that finishes the input too.
+pub fn prompt_multiline(msg: &str, terminator: Option<&str>) -> Vec<String> {
+    ask(msg, Hint::new(AnswerKind::Text));
+    let mut lines = Vec::new();
+    loop {
+        let line = read_line();
+        if line.is_empty() || terminator.is_some_and(|t| line.eq_ignore_ascii_case(t)) {
+            return lines;
+        }
+        lines.push(line);
+        console::hint(&Hint::new(AnswerKind::Text));
+    }
+}
+
 /// Prompts user for a yes/no answer.
 ///
 /// Set parameter "numeric" to true if we want a 1 or 0 answer.
 pub fn prompt_bool(msg: &str, numeric: bool) -> bool {
     loop {
-        println!("{}", msg);
         if numeric {
+            ask(msg, Hint::new(AnswerKind::Number).range(&(0..=1)));
             match read_number::<u8>() {
                 Ok(n) => match n {
                     1 => return true,
                     0 => return false,
-                    _ => println!("ENTER 1 (YES) OR 0 (NO)"),
+                    _ => say!("{}", message(Message::EnterOneYesZeroNo)),
                 },
-                Err(_) => println!("ENTER A NUMBER (1 OR 0)"),
+                Err(_) => say!("{}", message(Message::EnterOneOrZero)),
             }
         } else {
+            ask(msg, Hint::new(AnswerKind::YesNo));
             match read_line().as_str() {
-                "YES" | "Y" => return true,
-                "NO" | "N" => return false,
-                _ => println!("ENTER (Y)ES OR (N)O"),
+                a if lang::is_yes(a) => return true,
+                a if lang::is_no(a) => return false,
+                _ => say!("{}", message(Message::EnterYesOrNo)),
             }
         }
     }
 }
 
+/// Asks user to confirm, e.g. "ARE YOU SURE?", expecting (Y)ES or (N)O.
+pub fn confirm(msg: &str) -> bool {
+    prompt_bool(msg, false)
+}
+
+/// Runs "prompt" and asks to confirm its answer with the message from "confirm_msg",
+/// prompting again until the user confirms.
+///
+/// e.g. prompt_confirmed(|| prompt_number("HOW MUCH?"), |v| format!("BET {v}, ARE YOU SURE?"))
+pub fn prompt_confirmed<T, P, C>(mut prompt: P, confirm_msg: C) -> T
+where
+    P: FnMut() -> T,
+    C: Fn(&T) -> String,
+{
+    loop {
+        let answer = prompt();
+        if confirm(&confirm_msg(&answer)) {
+            return answer;
+        }
+    }
+}
+
 /// Ask user for a number (of type T).
 pub fn prompt_number<T: FromStr<Err = ParseIntError>>(msg: &str) -> T {
+    prompt_number_with_error(msg, message(Message::EnterValidNumber))
+}
+
+/// Ask user for a number (of type T), printing "invalid" when the input isn't one.
+pub fn prompt_number_with_error<T: FromStr<Err = ParseIntError>>(msg: &str, invalid: &str) -> T {
     loop {
-        println!("{}", msg);
+        ask(msg, Hint::new(AnswerKind::Number));
         match read_number::<T>() {
             Ok(n) => return n,
-            Err(_) => println!("ENTER A VALID NUMBER"),
+            Err(_) => say!("{}", invalid),
         }
     }
 }
 
+/// Splits an answer like "100 DOLLARS" into the leading number and the unit word after it.
+///
+/// The number is everything up to the first whitespace, so "2.5 ACRES" works for floats.
+/// Returns None if that isn't a number (like "1,000" or "12ABC")
+/// or if more than one word, or anything but letters, follows it.
+pub fn parse_number_with_unit<T: FromStr>(input: &str) -> Option<(T, &str)> {
+    let mut parts = input.trim().splitn(2, char::is_whitespace);
+    let n = parts.next()?.parse::<T>().ok()?;
+    let unit = parts.next().unwrap_or_default().trim();
+    if !unit.chars().all(char::is_alphabetic) {
+        return None;
+    }
+    Some((n, unit))
+}
+
+/// Ask user for a number (of type T), allowing a unit word after it, e.g. "5 BUSHELS".
+///
+/// If "units" isn't empty the unit word, when given, must be one of them.
+pub fn prompt_number_with_unit<T: FromStr<Err = ParseIntError>>(msg: &str, units: &[&str]) -> T {
+    loop {
+        ask(msg, Hint::new(AnswerKind::Number));
+        match parse_number_with_unit::<T>(&read_line()) {
+            Some((n, unit)) => {
+                if unit.is_empty()
+                    || units.is_empty()
+                    || units.iter().any(|u| u.eq_ignore_ascii_case(unit))
+                {
+                    return n;
+                }
+                let or = format!(" {} ", message(Message::Or));
+                say!(
+                    "{}",
+                    message_with(Message::EnterNumberOfUnits, &[&units.join(&or)])
+                );
+            }
+            None => say!("{}", message(Message::EnterValidNumber)),
+        }
+    }
+}
+
+/// Runs "prompt" and counts the answers it took, 1 if the first answer was valid.
+///
+/// e.g. let (guess, attempts) = prompt_with_attempts(|| prompt_number_range("GUESS?", 1..=100));
+pub fn prompt_with_attempts<T, P: FnOnce() -> T>(prompt: P) -> (T, u32) {
+    let before = ANSWERS_READ.with(Cell::get);
+    let value = prompt();
+    let attempts = ANSWERS_READ.with(Cell::get).wrapping_sub(before);
+    (value, attempts)
+}
+
+/// Like prompt_number, also returning how many answers it took.
+pub fn prompt_number_attempts<T: FromStr<Err = ParseIntError>>(msg: &str) -> (T, u32) {
+    prompt_with_attempts(|| prompt_number(msg))
+}
+
+/// Like prompt_number_range, also returning how many answers it took.
+pub fn prompt_number_range_attempts<T>(msg: &str, range: RangeInclusive<T>) -> (T, u32)
+where
+    T: FromStr<Err = ParseIntError> + PartialOrd + Display + Debug,
+{
+    prompt_with_attempts(|| prompt_number_range(msg, range))
+}
+
 /// Asks user for a number <T> in specified range.
 pub fn prompt_number_range<T>(msg: &str, range: RangeInclusive<T>) -> T
+where
+    T: FromStr<Err = ParseIntError> + PartialOrd + Display + Debug,
+{
+    number_range(msg, range, message(Message::EnterValidNumber), None)
+}
+
+/// Asks user for a number <T> in specified range, with custom messages
+/// for input that isn't a number ("invalid") and numbers outside the range ("out_of_range").
+pub fn prompt_number_range_with_errors<T>(
+    msg: &str,
+    range: RangeInclusive<T>,
+    invalid: &str,
+    out_of_range: &str,
+) -> T
+where
+    T: FromStr<Err = ParseIntError> + PartialOrd + Display + Debug,
+{
+    number_range(msg, range, invalid, Some(out_of_range))
+}
+
+/// Asks user for a number <T> in specified range that is a multiple of "step",
+/// e.g. 5 for bets in chips of 5 or 2 for even numbers only.
+///
+/// Panics if "step" is 0.
+pub fn prompt_number_range_step<T>(msg: &str, range: RangeInclusive<T>, step: T) -> T
+where
+    T: FromStr<Err = ParseIntError>
+        + PartialOrd
+        + Display
+        + Debug
+        + Copy
+        + Default
+        + Rem<Output = T>,
+{
+    let zero = T::default();
+    assert!(step != zero, "step must not be 0");
+    loop {
+        let n = number_range(msg, range.clone(), message(Message::EnterValidNumber), None);
+        if n % step == zero {
+            return n;
+        }
+        if "2".parse::<T>().is_ok_and(|two| two == step) {
+            say!("{}", message(Message::EnterEvenNumber));
+        } else {
+            say!("{}", message_with(Message::EnterMultipleOf, &[&step]));
+        }
+    }
+}
+
+fn number_range<T>(
+    msg: &str,
+    range: RangeInclusive<T>,
+    invalid: &str,
+    out_of_range: Option<&str>,
+) -> T
 where
     T: FromStr<Err = ParseIntError> + PartialOrd + Display + Debug,
 {
     loop {
-        println!("{}", msg);
+        ask(msg, Hint::new(AnswerKind::Number).range(&range));
         match read_number::<T>() {
             Ok(n) => {
                 if range.contains(&n) {
                     return n;
                 }
-                println!(
-                    "ENTER A NUMBER WITHIN {:?}, AND {:?}",
-                    range.start(),
-                    range.end()
-                );
+                match out_of_range {
+                    Some(m) => say!("{}", m),
+                    None => say!("{}", within(Message::NumberWithin, &range)),
+                }
+            }
+            Err(_) => say!("{}", invalid),
+        }
+    }
+}
+
+/// Ask user for a decimal number.
+///
+/// Exponent forms like "1.5E3" or "2E-2" are accepted, like in BASIC.
+pub fn prompt_float(msg: &str) -> f64 {
+    loop {
+        ask(msg, Hint::new(AnswerKind::Float));
+        match read_float() {
+            Some(n) => return n,
+            None => say!("{}", message(Message::EnterValidNumber)),
+        }
+    }
+}
+
+/// Asks user for a decimal number in specified range.
+///
+/// Exponent forms like "1.5E3" or "2E-2" are accepted, like in BASIC.
+pub fn prompt_float_range(msg: &str, range: RangeInclusive<f64>) -> f64 {
+    loop {
+        ask(msg, Hint::new(AnswerKind::Float).range(&range));
+        match read_float() {
+            Some(n) => {
+                if range.contains(&n) {
+                    return n;
+                }
+                say!("{}", within(Message::NumberWithin, &range));
             }
-            Err(_) => println!("ENTER A VALID NUMBER"),
+            None => say!("{}", message(Message::EnterValidNumber)),
+        }
+    }
+}
+
+/// Keeps asking user for answers (of type T) until the "stop_word" is entered.
+///
+/// Returns the answers in the order they were given.
+pub fn prompt_until<T: FromStr>(msg: &str, stop_word: &str) -> Vec<T> {
+    let mut answers = Vec::new();
+    loop {
+        ask(msg, Hint::new(AnswerKind::Text));
+        let input = read_line();
+        if input.eq_ignore_ascii_case(stop_word) {
+            return answers;
+        }
+        match input.parse::<T>() {
+            Ok(a) => answers.push(a),
+            Err(_) => say!(
+                "{}",
+                message_with(Message::EnterValidAnswerOr, &[&stop_word.to_uppercase()])
+            ),
+        }
+    }
+}
+
+/// Answers faster than this were typed before the cue.
+const MIN_REACTION: Duration = Duration::from_millis(100);
+
+/// Prints "msg", waits 1 to 5 seconds, prints "NOW!" and measures how fast the user presses ENTER.
+///
+/// Pressing ENTER before the cue doesn't count, the user is told off and it starts over.
+pub fn prompt_reaction(msg: &str) -> Duration {
+    loop {
+        say!("{}", msg);
+        thread::sleep(Duration::from_secs_f64(1.0 + rng::rnd() * 4.0));
+        ask(message(Message::Now), Hint::new(AnswerKind::Text));
+        let cue = Instant::now();
+        read_line();
+        let reaction = cue.elapsed();
+        if reaction >= MIN_REACTION {
+            return reaction;
         }
+        say!("{}", message(Message::TooSoon));
     }
 }
 
@@ -152,6 +606,17 @@ pub enum PromptMultiOption {
     UnitAmountRange(RangeInclusive<usize>),
 }
 
+/// A range message, e.g. "ENTER A NUMBER WITHIN 1, AND 10".
+fn within<T: Debug>(m: Message, range: &RangeInclusive<T>) -> String {
+    message_with(
+        m,
+        &[
+            &format!("{:?}", range.start()),
+            &format!("{:?}", range.end()),
+        ],
+    )
+}
+
 fn check_multi_option(o: &PromptMultiOption, l: usize) -> bool {
     use PromptMultiOption::*;
 
@@ -160,18 +625,14 @@ fn check_multi_option(o: &PromptMultiOption, l: usize) -> bool {
             if l == *a {
                 return true;
             } else {
-                println!("THERE MUST BE {a} UNITS")
+                say!("{}", message_with(Message::UnitsExactly, &[a]))
             }
         }
         UnitAmountRange(r) => {
             if r.contains(&l) {
                 return true;
             } else {
-                println!(
-                    "AMOUNT OF UNITS MUST BE WITHIN {:?} AND {:?}",
-                    r.start(),
-                    r.end()
-                );
+                say!("{}", within(Message::UnitsWithin, r));
             }
         }
     }
@@ -188,13 +649,13 @@ pub fn prompt_multi_string(
     option: Option<PromptMultiOption>,
 ) -> Vec<String> {
     loop {
-        println!("{}", msg);
+        ask(msg, Hint::new(AnswerKind::List));
 
         let input = read_line();
         let input: Vec<String> = input.split(separator).map(str::to_string).collect();
 
         if let Some(o) = &option {
-            if check_multi_option(&o, input.len()) {
+            if check_multi_option(o, input.len()) {
                 return input;
             }
         } else {
@@ -218,46 +679,212 @@ where
     T: FromStr + PartialOrd + Debug,
 {
     loop {
-        println!("{}", msg);
+        let hint = Hint::new(AnswerKind::List);
+        ask(
+            msg,
+            match &range {
+                Some(r) => hint.range(r),
+                None => hint,
+            },
+        );
 
         let input = read_line();
         let input: Vec<&str> = input.split(separator).collect();
 
-        let mut ok = if let Some(o) = &option {
-            check_multi_option(&o, input.len())
+        let ok = if let Some(o) = &option {
+            check_multi_option(o, input.len())
         } else {
             true
         };
 
-        let mut nums = Vec::new();
-
         if ok {
-            for i in &input {
-                match i.parse::<T>() {
-                    Ok(n) => {
-                        if let Some(r) = &range {
-                            if r.contains(&n) {
-                                nums.push(n);
-                            } else {
-                                println!("NUMBER MUST BE WITHIN {:?} AND {:?}", r.start(), r.end());
-                                ok = false;
-                                break;
-                            }
-                        } else {
-                            nums.push(n);
-                        }
-                    }
-                    Err(_) => {
-                        println!("ENTER ONLY NUMBERS");
-                        ok = false;
-                        break;
-                    }
-                }
+            if let Some(nums) = parse_multi_number(&input, |_| range.as_ref()) {
+                return nums;
             }
         }
+    }
+}
 
-        if ok {
-            return nums;
+/// Asks user for one number(T) per range, units separated by the "separator",
+/// each number within the range at its position.
+///
+/// e.g. prompt_multi_number_ranges("ENTER ROW (1-8) AND COLUMN (1-10)", ",", &[1..=8, 1..=10])
+pub fn prompt_multi_number_ranges<T>(
+    msg: &str,
+    separator: &str,
+    ranges: &[RangeInclusive<T>],
+) -> Vec<T>
+where
+    T: FromStr + PartialOrd + Debug,
+{
+    loop {
+        ask(msg, Hint::new(AnswerKind::List));
+
+        let input = read_line();
+        let input: Vec<&str> = input.split(separator).collect();
+
+        if check_multi_option(&PromptMultiOption::UnitAmount(ranges.len()), input.len()) {
+            if let Some(nums) = parse_multi_number(&input, |i| ranges.get(i)) {
+                return nums;
+            }
+        }
+    }
+}
+
+/// Parses every unit as a number within the range for its position (if any).
+///
+/// Returns None after saying what's wrong with each bad unit,
+/// e.g. "'TWELVE' IS NOT A NUMBER (ITEM 3 OF 5)".
+fn parse_multi_number<'r, T, R>(input: &[&str], range_at: R) -> Option<Vec<T>>
+where
+    T: FromStr + PartialOrd + Debug + 'r,
+    R: Fn(usize) -> Option<&'r RangeInclusive<T>>,
+{
+    let count = input.len();
+    let mut nums = Vec::new();
+    let mut errors = Vec::new();
+    for (i, unit) in input.iter().enumerate() {
+        match unit.parse::<T>() {
+            Ok(n) => match range_at(i) {
+                Some(r) if !r.contains(&n) => errors.push(message_with(
+                    Message::NotWithin,
+                    &[
+                        unit,
+                        &format!("{:?}", r.start()),
+                        &format!("{:?}", r.end()),
+                        &(i + 1),
+                        &count,
+                    ],
+                )),
+                _ => nums.push(n),
+            },
+            Err(_) => errors.push(message_with(Message::NotANumber, &[unit, &(i + 1), &count])),
         }
     }
+
+    if errors.is_empty() {
+        return Some(nums);
+    }
+    for error in errors {
+        say!("{}", error);
+    }
+    None
+}
+
+/// Asks user for exactly N numbers(T), units separated by the "separator".
+///
+/// You can also optionally set a range in which the individual numbers should be.
+pub fn prompt_numbers<T, const N: usize>(
+    msg: &str,
+    separator: &str,
+    range: Option<RangeInclusive<T>>,
+) -> [T; N]
+where
+    T: FromStr + PartialOrd + Debug,
+{
+    let nums = prompt_multi_number(
+        msg,
+        separator,
+        Some(PromptMultiOption::UnitAmount(N)),
+        range,
+    );
+    nums.try_into()
+        .expect("prompt_multi_number returns exactly N units")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use console::ScriptedConsole;
+
+    #[test]
+    fn running_out_of_input_quits_the_game() {
+        // a channel console whose frontend is gone has no more input
+        let (hung_up, _, _) = channel::channel_console();
+        let previous = console::set_console(Box::new(hung_up));
+
+        let quit_ran = Rc::new(RefCell::new(false));
+        let flag = quit_ran.clone();
+        let result = quit::run_game(|| {
+            quit::on_quit(move || *flag.borrow_mut() = true);
+            prompt_number::<i32>("HOW MANY?")
+        });
+        console::set_console(previous);
+
+        assert_eq!(result, None);
+        assert!(*quit_ran.borrow());
+    }
+
+    /// Plays "game" with scripted answers, returning its result and the transcript.
+    fn scripted<T>(answers: &[&str], game: impl FnOnce() -> T) -> (T, String) {
+        let console = ScriptedConsole::new(answers.iter().copied());
+        let transcript = console.transcript();
+        let previous = console::set_console(Box::new(console));
+        lang::pin_lang(Some(Lang::English));
+        let result = game();
+        console::set_console(previous);
+        (result, transcript.text())
+    }
+
+    #[test]
+    fn says_what_is_wrong_with_each_number() {
+        let (nums, transcript) = scripted(&["5,TWELVE,50", "5,12,20"], || {
+            prompt_multi_number("AGES?", ",", None, Some(1..=20))
+        });
+        assert_eq!(nums, [5, 12, 20]);
+        assert_eq!(
+            transcript,
+            "AGES?\n5,TWELVE,50\n\
+             'TWELVE' IS NOT A NUMBER (ITEM 2 OF 3)\n\
+             '50' IS NOT WITHIN 1 AND 20 (ITEM 3 OF 3)\n\
+             AGES?\n5,12,20\n"
+        );
+    }
+
+    #[test]
+    fn checks_each_number_against_its_own_range() {
+        let (nums, transcript) = scripted(&["4,9", "3,10", "3,9,1", "3,9"], || {
+            prompt_multi_number_ranges("ROW, COLUMN?", ",", &[1..=3, 1..=9])
+        });
+        assert_eq!(nums, [3, 9]);
+        assert!(
+            transcript.starts_with(
+                "ROW, COLUMN?\n4,9\n\
+                 '4' IS NOT WITHIN 1 AND 3 (ITEM 1 OF 2)\n\
+                 ROW, COLUMN?\n3,10\n\
+                 '10' IS NOT WITHIN 1 AND 9 (ITEM 2 OF 2)\n\
+                 ROW, COLUMN?\n3,9,1\n"
+            ),
+            "{transcript}"
+        );
+        // too many numbers is refused before they're checked
+        assert!(transcript.ends_with("ROW, COLUMN?\n3,9\n"), "{transcript}");
+    }
+
+    #[test]
+    fn parses_number_with_unit() {
+        assert_eq!(parse_number_with_unit::<i32>("100"), Some((100, "")));
+        assert_eq!(
+            parse_number_with_unit::<i32>(" -5  bushels "),
+            Some((-5, "bushels"))
+        );
+        assert_eq!(parse_number_with_unit::<f64>("2.5"), Some((2.5, "")));
+        assert_eq!(
+            parse_number_with_unit::<f64>("1.5 DOLLARS"),
+            Some((1.5, "DOLLARS"))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_number_with_unit() {
+        assert_eq!(parse_number_with_unit::<i32>("1,000"), None);
+        assert_eq!(parse_number_with_unit::<i32>("12ABC"), None);
+        assert_eq!(parse_number_with_unit::<i32>("2.5"), None);
+        assert_eq!(parse_number_with_unit::<i32>("5 GOLD BARS"), None);
+        assert_eq!(parse_number_with_unit::<i32>("5 $"), None);
+        assert_eq!(parse_number_with_unit::<i32>("DOLLARS"), None);
+        assert_eq!(parse_number_with_unit::<i32>(""), None);
+    }
 }
@@ -0,0 +1,114 @@
+use std::env;
+
+use crate::{accessibility::is_accessible, console};
+
+/// Width that "{center}" lines are centered in.
+pub const LINE_WIDTH: usize = 70;
+
+/// Whether styled (ANSI) output should be used.
+///
+/// Not if the console isn't a terminal, TERM is "dumb", NO_COLOR is set
+/// or accessibility mode is on.
+pub fn ansi_supported() -> bool {
+    console::ansi()
+        && env::var_os("NO_COLOR").is_none()
+        && env::var("TERM").map_or(true, |t| t != "dumb")
+        && !is_accessible()
+}
+
+/// Renders a line of instruction markup.
+///
+/// "*bold*" and "_underline_" are styled with ANSI codes if "ansi" is true and stripped otherwise.
+/// A line starting with "{center}" is centered. A "\" before "*", "_" or "\" keeps it as is.
+pub fn render_markup(line: &str, ansi: bool) -> String {
+    let (line, center) = match line.strip_prefix("{center}") {
+        Some(rest) => (rest, true),
+        None => (line, false),
+    };
+
+    let mut out = String::with_capacity(line.len());
+    let mut visible = 0;
+    let mut bold = false;
+    let mut underline = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(e @ ('*' | '_' | '\\')) => {
+                    out.push(e);
+                    visible += 1;
+                }
+                Some(other) => {
+                    out.push('\\');
+                    out.push(other);
+                    visible += 2;
+                }
+                None => {
+                    out.push('\\');
+                    visible += 1;
+                }
+            },
+            '*' => {
+                bold = !bold;
+                if ansi {
+                    out.push_str(if bold { "\x1b[1m" } else { "\x1b[22m" });
+                }
+            }
+            '_' => {
+                underline = !underline;
+                if ansi {
+                    out.push_str(if underline { "\x1b[4m" } else { "\x1b[24m" });
+                }
+            }
+            c => {
+                out.push(c);
+                visible += 1;
+            }
+        }
+    }
+
+    if ansi && (bold || underline) {
+        out.push_str("\x1b[0m");
+    }
+
+    if center && visible < LINE_WIDTH {
+        format!("{}{}", " ".repeat((LINE_WIDTH - visible) / 2), out)
+    } else {
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_markup_without_ansi() {
+        assert_eq!(render_markup("A *BOLD* _LINE_", false), "A BOLD LINE");
+    }
+
+    #[test]
+    fn styles_markup_with_ansi() {
+        assert_eq!(
+            render_markup("*B* _U_", true),
+            "\x1b[1mB\x1b[22m \x1b[4mU\x1b[24m"
+        );
+        // unclosed styles are reset at the end of the line
+        assert_eq!(render_markup("*B", true), "\x1b[1mB\x1b[0m");
+    }
+
+    #[test]
+    fn keeps_escaped_characters() {
+        assert_eq!(render_markup(r"2 \* 3 \_ \\ \n \", true), r"2 * 3 _ \ \n \");
+    }
+
+    #[test]
+    fn centers_lines() {
+        let line = render_markup("{center}*TITLE*", true);
+        assert_eq!(line, format!("{}\x1b[1mTITLE\x1b[22m", " ".repeat(32)));
+
+        let long = "X".repeat(LINE_WIDTH + 1);
+        assert_eq!(render_markup(&format!("{{center}}{long}"), false), long);
+    }
+}
@@ -0,0 +1,185 @@
+use std::{fmt, ops::RangeInclusive};
+
+use crate::{
+    lang::{message, message_with, Message},
+    prompt_number_range, prompt_string,
+};
+
+/// A player's name as they typed it, e.g. "Alice".
+///
+/// Displays as typed, use upper() for the all capitals of the classic games ("HELLO, ALICE").
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PlayerName(String);
+
+impl PlayerName {
+    /// None if the name is blank.
+    pub fn new(name: &str) -> Option<Self> {
+        let name = name.trim();
+        (!name.is_empty()).then(|| PlayerName(name.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The name in capitals.
+    pub fn upper(&self) -> String {
+        self.0.to_uppercase()
+    }
+}
+
+impl fmt::Display for PlayerName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<PlayerName> for String {
+    fn from(name: PlayerName) -> Self {
+        name.0
+    }
+}
+
+/// A player taking part in a hot-seat game.
+pub struct Player {
+    pub name: String,
+    pub eliminated: bool,
+}
+
+/// Turn rotation for N players sharing one terminal.
+///
+/// Eliminated players are skipped when the turn passes on.
+pub struct TurnOrder {
+    players: Vec<Player>,
+    current: usize,
+}
+
+impl TurnOrder {
+    /// Panics if there are no players.
+    pub fn new(names: Vec<String>) -> Self {
+        assert!(!names.is_empty(), "TurnOrder needs at least one player");
+        TurnOrder {
+            players: names
+                .into_iter()
+                .map(|name| Player {
+                    name,
+                    eliminated: false,
+                })
+                .collect(),
+            current: 0,
+        }
+    }
+
+    /// Asks for the number of players (within "amount") and then each player's name.
+    ///
+    /// Blank names default to "PLAYER <N>". There's always at least one player,
+    /// so panics if "amount" doesn't allow any.
+    pub fn prompt(amount: RangeInclusive<usize>) -> Self {
+        let amount = (*amount.start()).max(1)..=*amount.end();
+        assert!(!amount.is_empty(), "TurnOrder needs at least one player");
+        let n = prompt_number_range(message(Message::HowManyPlayers), amount);
+        let names = (1..=n)
+            .map(|i| {
+                let name = prompt_string(&message_with(Message::PlayerName, &[&i]));
+                if name.is_empty() {
+                    message_with(Message::Player, &[&i])
+                } else {
+                    name
+                }
+            })
+            .collect();
+        TurnOrder::new(names)
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// Index of the player whose turn it is.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.current]
+    }
+
+    /// Passes the turn to the next player still in the game and returns their index.
+    pub fn advance(&mut self) -> usize {
+        for _ in 0..self.players.len() {
+            self.current = (self.current + 1) % self.players.len();
+            if !self.players[self.current].eliminated {
+                break;
+            }
+        }
+        self.current
+    }
+
+    pub fn eliminate(&mut self, player: usize) {
+        self.players[player].eliminated = true;
+    }
+
+    /// Amount of players not eliminated.
+    pub fn remaining(&self) -> usize {
+        self.players.iter().filter(|p| !p.eliminated).count()
+    }
+
+    /// The last player standing, if only one is left.
+    pub fn winner(&self) -> Option<&Player> {
+        let mut left = self.players.iter().filter(|p| !p.eliminated);
+        match (left.next(), left.next()) {
+            (Some(p), None) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Prints "<NAME>, IT'S YOUR TURN".
+    pub fn print_turn(&self) {
+        say!("{}", self.render_turn());
+    }
+
+    pub fn render_turn(&self) -> String {
+        format!(
+            "\n{}",
+            message_with(Message::YourTurn, &[&self.current_player().name])
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        console::{self, ScriptedConsole},
+        lang::{self, Lang},
+    };
+
+    #[test]
+    #[should_panic(expected = "at least one player")]
+    fn needs_a_player() {
+        TurnOrder::new(Vec::new());
+    }
+
+    #[test]
+    fn prompts_for_at_least_one_player() {
+        lang::pin_lang(Some(Lang::English));
+        let scripted = ScriptedConsole::new(["0", "2", "ANN", ""]);
+        let previous = console::set_console(Box::new(scripted));
+        let order = TurnOrder::prompt(0..=3);
+        console::set_console(previous);
+
+        let names: Vec<&str> = order.players().iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["ANN", "PLAYER 2"]);
+        assert_eq!(order.render_turn(), "\nANN, IT'S YOUR TURN");
+    }
+
+    #[test]
+    fn skips_eliminated_players() {
+        let mut order = TurnOrder::new(vec!["A".into(), "B".into(), "C".into()]);
+        order.eliminate(1);
+        assert_eq!(order.advance(), 2);
+        assert_eq!(order.advance(), 0);
+        order.eliminate(2);
+        assert_eq!(order.winner().map(|p| p.name.as_str()), Some("A"));
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    cell::Cell,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{accessibility::is_accessible, console};
+
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+thread_local! {
+    /// The quarter last announced in accessibility mode.
+    static ANNOUNCED: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Prints a progress bar like "[#####.....]  50%", redrawn in place until it's complete.
+///
+/// In accessibility mode only "50%" is printed, on its own line and only for each quarter reached.
+pub fn print_progress_bar(current: u64, total: u64, width: usize) {
+    if is_accessible() {
+        let quarter = percent(current, total) / 25;
+        if current == 0 || ANNOUNCED.get() != Some(quarter) {
+            ANNOUNCED.set(Some(quarter));
+            say!("{}%", quarter * 25);
+        }
+        return;
+    }
+
+    let bar = render_progress_bar(current, total, width);
+    if current >= total {
+        console::write(&format!("\r{bar}\n"));
+    } else {
+        console::write(&format!("\r{bar}"));
+    }
+}
+
+/// The progress bar of print_progress_bar as text, "width" is the number of cells inside the brackets.
+pub fn render_progress_bar(current: u64, total: u64, width: usize) -> String {
+    let done = (percent(current, total) as usize * width) / 100;
+    format!(
+        "[{}{}] {:>3}%",
+        "#".repeat(done),
+        ".".repeat(width - done),
+        percent(current, total)
+    )
+}
+
+fn percent(current: u64, total: u64) -> u64 {
+    if total == 0 || current >= total {
+        100
+    } else {
+        (current as u128 * 100 / total as u128) as u64
+    }
+}
+
+/// Shows "msg" with a spinning line after it for "duration", e.g. while "COMPUTING TRAJECTORY...".
+///
+/// Slower output (like the Delay middleware) means fewer turns, not a longer wait.
+/// In accessibility mode "msg" is printed once and the spinner is left out.
+pub fn spinner(msg: &str, duration: Duration) {
+    if is_accessible() {
+        say!("{}", msg);
+        thread::sleep(duration);
+        return;
+    }
+
+    let start = Instant::now();
+    for frame in SPINNER_FRAMES.iter().cycle() {
+        let left = duration.saturating_sub(start.elapsed());
+        if left.is_zero() {
+            break;
+        }
+        console::write(&format!("\r{msg} {frame}"));
+        thread::sleep(left.min(SPINNER_INTERVAL));
+    }
+    console::write(&format!("\r{msg}  \n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{accessibility, console::ScriptedConsole};
+
+    #[test]
+    fn renders_the_bar() {
+        assert_eq!(render_progress_bar(0, 10, 10), "[..........]   0%");
+        assert_eq!(render_progress_bar(5, 10, 10), "[#####.....]  50%");
+        assert_eq!(render_progress_bar(12, 10, 4), "[####] 100%");
+        assert_eq!(render_progress_bar(1, 0, 4), "[####] 100%");
+        assert_eq!(
+            render_progress_bar(u64::MAX / 2, u64::MAX, 10),
+            "[####......]  49%"
+        );
+    }
+
+    #[test]
+    fn announces_each_quarter_once() {
+        accessibility::pin_accessible(Some(true));
+        let scripted = ScriptedConsole::new(Vec::<String>::new());
+        let transcript = scripted.transcript();
+        let previous = console::set_console(Box::new(scripted));
+        for current in (0..=100).step_by(10) {
+            print_progress_bar(current, 100, 10);
+        }
+        console::set_console(previous);
+        accessibility::pin_accessible(None);
+
+        assert_eq!(transcript.text(), "0%\n25%\n50%\n75%\n100%\n");
+    }
+}
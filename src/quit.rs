@@ -0,0 +1,115 @@
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    process,
+};
+
+thread_local! {
+    static GAMES_RUNNING: Cell<usize> = const { Cell::new(0) };
+    static QUIT_WORD: RefCell<Option<String>> = const { RefCell::new(None) };
+    static CLEANUP: RefCell<Vec<Box<dyn FnOnce()>>> = RefCell::new(Vec::new());
+}
+
+/// Unwinding payload of a quit, caught by run_game.
+pub struct Quit;
+
+/// Sets the word (like "STOP") that quits the game when answered to any prompt, None turns it off.
+///
+/// Under run_game (the runners of the channel, jsonrpc and chat modules use it) quitting
+/// goes back to where run_game was called, otherwise it ends the program with exit code 0.
+pub fn set_quit_word(word: Option<&str>) {
+    QUIT_WORD.with(|w| *w.borrow_mut() = word.map(str::to_uppercase));
+}
+
+pub(crate) fn quit_word() -> Option<String> {
+    QUIT_WORD.with(|w| w.borrow().clone())
+}
+
+pub(crate) fn is_quit_word(answer: &str) -> bool {
+    QUIT_WORD.with(|w| {
+        w.borrow()
+            .as_ref()
+            .is_some_and(|w| answer.eq_ignore_ascii_case(w))
+    })
+}
+
+/// Registers a callback to run when the game is quit, e.g. to save, print the score or say goodbye.
+///
+/// Callbacks run in the order they were registered.
+pub fn on_quit<F: FnOnce() + 'static>(cleanup: F) {
+    CLEANUP.with(|c| c.borrow_mut().push(Box::new(cleanup)));
+}
+
+/// Runs the cleanup callbacks and leaves the game, back to where run_game was called.
+///
+/// Without run_game this ends the program with exit code 0, like the END of a BASIC listing.
+///
+/// This is what answering the quit word does,
+/// and what happens when the console has no more input.
+pub fn quit() -> ! {
+    let callbacks = CLEANUP.with(|c| std::mem::take(&mut *c.borrow_mut()));
+    for cleanup in callbacks {
+        cleanup();
+    }
+    if GAMES_RUNNING.get() == 0 {
+        process::exit(0);
+    }
+    panic::resume_unwind(Box::new(Quit));
+}
+
+/// Runs a game, returning its result or None if it was quit.
+///
+/// Callbacks registered with on_quit are dropped once the game is over.
+pub fn run_game<T, F: FnOnce() -> T>(game: F) -> Option<T> {
+    GAMES_RUNNING.set(GAMES_RUNNING.get() + 1);
+    let result = panic::catch_unwind(AssertUnwindSafe(game));
+    GAMES_RUNNING.set(GAMES_RUNNING.get() - 1);
+    CLEANUP.with(|c| c.borrow_mut().clear());
+    match result {
+        Ok(value) => Some(value),
+        Err(payload) if payload.is::<Quit>() => None,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn run_game_returns_the_result() {
+        assert_eq!(run_game(|| 42), Some(42));
+    }
+
+    #[test]
+    fn quitting_returns_none_after_the_callbacks_in_order() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let result = run_game(|| {
+            for name in ["SAVE", "SCORE", "GOODBYE"] {
+                let calls = calls.clone();
+                on_quit(move || calls.borrow_mut().push(name));
+            }
+            quit();
+        });
+        assert_eq!(result, None::<()>);
+        assert_eq!(*calls.borrow(), ["SAVE", "SCORE", "GOODBYE"]);
+        assert_eq!(GAMES_RUNNING.get(), 0);
+    }
+
+    #[test]
+    fn callbacks_are_dropped_after_the_game() {
+        let calls = Rc::new(RefCell::new(0));
+        let counter = calls.clone();
+        run_game(|| on_quit(move || *counter.borrow_mut() += 1));
+        assert_eq!(run_game(|| quit()), None::<()>);
+        assert_eq!(*calls.borrow(), 0);
+    }
+
+    #[test]
+    fn nested_games_quit_the_inner_one() {
+        let outer = run_game(|| run_game(|| quit()).is_none());
+        assert_eq!(outer, Some(true));
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    cell::Cell,
+    ops::RangeInclusive,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+thread_local! {
+    static STATE: Cell<u64> = Cell::new(time_seed());
+}
+
+fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0x853C_49E6_748F_EA9B)
+}
+
+/// Seeds the random number generator, so a game can be replayed exactly.
+pub fn seed(seed: u64) {
+    STATE.with(|s| s.set(seed));
+}
+
+/// The generator's state, seeding with it continues the same sequence.
+pub(crate) fn state() -> u64 {
+    STATE.with(Cell::get)
+}
+
+fn next_u64() -> u64 {
+    // splitmix64
+    STATE.with(|s| {
+        let state = s.get().wrapping_add(0x9E37_79B9_7F4A_7C15);
+        s.set(state);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    })
+}
+
+/// Random number between 0 (inclusive) and 1 (exclusive), like BASIC's RND(1).
+pub fn rnd() -> f64 {
+    (next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Random integer between 0 (inclusive) and "n" (exclusive).
+///
+/// Panics if "n" is 0.
+pub fn below(n: usize) -> usize {
+    assert!(n > 0, "below(0) has no possible values");
+    (next_u64() % n as u64) as usize
+}
+
+/// Picks one of the items, each with a chance in proportion to its weight.
+///
+/// e.g. choose_weighted(&[("PLAGUE", 0.1), ("HARVEST", 0.3), ("NOTHING", 0.6)])
+///
+/// Returns None if there are no items or no positive weights, negative weights count as 0.
+pub fn choose_weighted<T>(items: &[(T, f64)]) -> Option<&T> {
+    let total: f64 = items.iter().map(|(_, w)| w.max(0.0)).sum();
+    if total <= 0.0 || !total.is_finite() {
+        return None;
+    }
+
+    let mut roll = rnd() * total;
+    for (item, weight) in items {
+        let weight = weight.max(0.0);
+        if roll < weight {
+            return Some(item);
+        }
+        roll -= weight;
+    }
+    // rounding can leave a tiny rest, it belongs to the last item with weight
+    items.iter().rev().find(|(_, w)| *w > 0.0).map(|(i, _)| i)
+}
+
+/// Shuffles the items in place (Fisher-Yates), the same way for the same seed.
+pub fn shuffle<T>(items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, below(i + 1));
+    }
+}
+
+/// Random integer from the range, both ends included, as INT(RND(1)*(B-A+1))+A.
+///
+/// Ranges of more than 2^32 numbers, which the formula can't cover evenly, use integer math.
+/// Panics if the range is empty.
+pub fn rnd_range(range: RangeInclusive<i64>) -> i64 {
+    let (start, end) = range.into_inner();
+    assert!(start <= end, "rnd_range({start}..={end}) is empty");
+    // one less than the number of values, so the whole i64 range fits
+    let span = (end as u64).wrapping_sub(start as u64);
+    let offset = if span < 1 << 32 {
+        (rnd() * (span + 1) as f64) as u64
+    } else if span == u64::MAX {
+        next_u64()
+    } else {
+        next_u64() % (span + 1)
+    };
+    start.wrapping_add(offset as i64)
+}
+
+/// Random integer from 1 to "n", the INT(RND(1)*N)+1 found all over the old listings.
+///
+/// Panics if "n" is less than 1.
+pub fn basic_rand(n: i64) -> i64 {
+    rnd_range(1..=n)
+}
+
+/// True with probability "p" (0 to 1), e.g. chance(0.15) for a 15% chance.
+pub fn chance(p: f64) -> bool {
+    rnd() < p
+}
+
+/// True "n" times in "m", e.g. chance_in(1, 6) for rolling a six.
+///
+/// Panics if "m" is 0.
+pub fn chance_in(n: usize, m: usize) -> bool {
+    below(m) < n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rnd_range_covers_the_whole_i64_range() {
+        seed(1);
+        let mut negative = false;
+        let mut positive = false;
+        for _ in 0..100 {
+            let n = rnd_range(i64::MIN..=i64::MAX);
+            negative |= n < 0;
+            positive |= n > 0;
+        }
+        assert!(negative && positive);
+        assert!((0..100).all(|_| rnd_range(-1..=i64::MAX) >= -1));
+    }
+
+    #[test]
+    fn rnd_range_of_one_value() {
+        seed(2);
+        assert_eq!(rnd_range(i64::MAX..=i64::MAX), i64::MAX);
+        assert_eq!(rnd_range(i64::MIN..=i64::MIN), i64::MIN);
+        assert_eq!(rnd_range(7..=7), 7);
+    }
+
+    #[test]
+    fn rnd_range_hits_both_ends() {
+        seed(3);
+        let values: Vec<i64> = (0..1000).map(|_| rnd_range(-5..=5)).collect();
+        assert!(values.iter().all(|n| (-5..=5).contains(n)));
+        assert!(values.contains(&-5) && values.contains(&5));
+    }
+
+    #[test]
+    fn rnd_range_matches_the_basic_formula() {
+        seed(4);
+        let expected: Vec<i64> = (0..10).map(|_| (rnd() * 6.0) as i64 + 1).collect();
+        seed(4);
+        let actual: Vec<i64> = (0..10).map(|_| rnd_range(1..=6)).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn basic_rand_stays_in_range() {
+        seed(5);
+        for n in [1, 2, 6, 100] {
+            assert!((0..200).all(|_| (1..=n).contains(&basic_rand(n))));
+        }
+    }
+
+    #[test]
+    fn choose_weighted_follows_the_weights() {
+        seed(6);
+        let items = [
+            ("NEVER", 0.0),
+            ("RARE", 1.0),
+            ("OFTEN", 9.0),
+            ("NEGATIVE", -5.0),
+        ];
+        let picks: Vec<&str> = (0..1000)
+            .map(|_| *choose_weighted(&items).unwrap())
+            .collect();
+        let often = picks.iter().filter(|p| **p == "OFTEN").count();
+        assert!(picks.iter().all(|p| *p == "RARE" || *p == "OFTEN"));
+        assert!((850..=950).contains(&often), "{often}");
+    }
+
+    #[test]
+    fn choose_weighted_without_positive_weights() {
+        assert_eq!(choose_weighted::<u8>(&[]), None);
+        assert_eq!(choose_weighted(&[(1, 0.0), (2, -1.0)]), None);
+        assert_eq!(choose_weighted(&[(1, f64::NAN)]), None);
+    }
+
+    #[test]
+    fn shuffle_is_repeatable_with_a_seed() {
+        let deck: Vec<u32> = (1..=52).collect();
+        let mut first = deck.clone();
+        seed(7);
+        shuffle(&mut first);
+        let mut second = deck.clone();
+        seed(7);
+        shuffle(&mut second);
+
+        assert_eq!(first, second);
+        assert_ne!(first, deck);
+        first.sort_unstable();
+        assert_eq!(first, deck);
+    }
+}
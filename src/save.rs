@@ -0,0 +1,84 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    lang::{message, Message},
+    prompt_bool,
+};
+
+/// Periodic checkpoints of game state in a local file.
+///
+/// Any state that implements Display and FromStr can be saved,
+/// so a game decides its own (usually one line) format.
+pub struct Checkpoint {
+    path: PathBuf,
+    every: u32,
+}
+
+impl Checkpoint {
+    /// Saves to "path" every "every" turns.
+    pub fn new<P: Into<PathBuf>>(path: P, every: u32) -> Self {
+        Checkpoint {
+            path: path.into(),
+            every: every.max(1),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.is_file()
+    }
+
+    /// Call once per turn, saves the state when a checkpoint is due.
+    ///
+    /// Returns true if the state was saved.
+    pub fn tick<T: Display>(&self, turn: u32, state: &T) -> io::Result<bool> {
+        if turn > 0 && turn.is_multiple_of(self.every) {
+            self.save(state)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Saves the state right away.
+    pub fn save<T: Display>(&self, state: &T) -> io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, state.to_string())?;
+        fs::rename(tmp, &self.path)
+    }
+
+    /// Loads the saved state, None if there is none or it can't be parsed.
+    pub fn load<T: FromStr>(&self) -> Option<T> {
+        fs::read_to_string(&self.path).ok()?.parse().ok()
+    }
+
+    /// Removes the checkpoint, e.g. when the game is over.
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Asks "RESUME PREVIOUS GAME?" if a checkpoint exists.
+    ///
+    /// Returns the saved state if the user wants to resume.
+    pub fn offer_resume<T: FromStr>(&self, numeric: bool) -> Option<T> {
+        if !self.exists() {
+            return None;
+        }
+        let state = self.load()?;
+        if prompt_bool(message(Message::ResumeGame), numeric) {
+            Some(state)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    accessibility::is_accessible,
+    lang::{message, Message},
+};
+
+/// Inner width of the final score box.
+const BOX_WIDTH: usize = 40;
+
+/// Points scored during a game, in labelled parts, with ratings for the total.
+///
+/// e.g. ScoreCard::new().add("GOLD", 120).add("SHIPS", 30).rating(100, "YOU ARE A SUPER STAR TRADER")
+#[derive(Clone, Debug, Default)]
+pub struct ScoreCard {
+    components: Vec<(String, i64)>,
+    ratings: Vec<(i64, String)>,
+}
+
+impl ScoreCard {
+    pub fn new() -> Self {
+        ScoreCard::default()
+    }
+
+    /// Adds points under "label", to the points already there if the label was used before.
+    pub fn add(mut self, label: &str, points: i64) -> Self {
+        self.add_points(label, points);
+        self
+    }
+
+    /// Like add, for a score card that's kept while the game runs.
+    pub fn add_points(&mut self, label: &str, points: i64) {
+        let label = label.to_uppercase();
+        match self.components.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, total)) => *total += points,
+            None => self.components.push((label, points)),
+        }
+    }
+
+    /// The message shown when the total is at least "threshold", the highest threshold reached wins.
+    pub fn rating(mut self, threshold: i64, message: &str) -> Self {
+        self.ratings.push((threshold, message.to_uppercase()));
+        self
+    }
+
+    pub fn components(&self) -> &[(String, i64)] {
+        &self.components
+    }
+
+    pub fn total(&self) -> i64 {
+        self.components.iter().map(|(_, points)| points).sum()
+    }
+
+    /// The rating reached by the total, None if it's below every threshold.
+    pub fn rated(&self) -> Option<&str> {
+        let total = self.total();
+        self.ratings
+            .iter()
+            .filter(|(threshold, _)| total >= *threshold)
+            .max_by_key(|(threshold, _)| *threshold)
+            .map(|(_, message)| message.as_str())
+    }
+}
+
+/// Prints the boxed final score summary, followed by the rating.
+///
+/// In accessibility mode the summary is printed without the box.
+pub fn print_final(card: &ScoreCard) {
+    say!("{}", render_final(card));
+}
+
+/// The summary of print_final as text.
+pub fn render_final(card: &ScoreCard) -> String {
+    let line = |label: &str, points: &str| {
+        let gap = BOX_WIDTH.saturating_sub(label.chars().count() + points.len() + 2);
+        format!("| {label}{}{points} |", " ".repeat(gap))
+    };
+    let total = card.total().to_string();
+
+    let mut lines = Vec::new();
+    if is_accessible() {
+        lines.push(message(Message::FinalScore).to_string());
+        for (label, points) in card.components() {
+            lines.push(format!("{label}: {points}"));
+        }
+        lines.push(format!("{}: {total}", message(Message::Total)));
+    } else {
+        let border = format!("+{}+", "-".repeat(BOX_WIDTH));
+        lines.push(border.clone());
+        lines.push(format!("|{:^BOX_WIDTH$}|", message(Message::FinalScore)));
+        lines.push(line("", ""));
+        for (label, points) in card.components() {
+            lines.push(line(label, &points.to_string()));
+        }
+        lines.push(line("", ""));
+        lines.push(line(message(Message::Total), &total));
+        lines.push(border);
+    }
+
+    if let Some(rating) = card.rated() {
+        lines.push(String::new());
+        lines.push(rating.to_string());
+    }
+    format!("\n{}", lines.join("\n"))
+}
@@ -0,0 +1,260 @@
+use std::{
+    fmt::Write as _,
+    ops::ControlFlow,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{accessibility::is_accessible, console};
+
+/// How many frames a loop may fall behind before it stops catching up.
+const MAX_CATCH_UP: u32 = 5;
+
+/// Calls "frame" at a fixed rate of "fps" frames per second, passing the frame number.
+///
+/// If a frame takes too long the following ones are run back to back to catch up,
+/// unless the loop fell too far behind, then it just carries on from now.
+///
+/// Return ControlFlow::Break to stop, its value is returned.
+pub fn run_frames<B, F>(fps: u32, mut frame: F) -> B
+where
+    F: FnMut(u64) -> ControlFlow<B>,
+{
+    let interval = Duration::from_secs(1) / fps.max(1);
+    let mut next = Instant::now();
+    let mut n = 0;
+
+    loop {
+        if let ControlFlow::Break(b) = frame(n) {
+            return b;
+        }
+        n += 1;
+        next += interval;
+
+        let now = Instant::now();
+        if next > now {
+            thread::sleep(next - now);
+        } else if now - next > interval * MAX_CATCH_UP {
+            next = now;
+        }
+    }
+}
+
+/// A double-buffered character screen.
+///
+/// Draw into it, then flush() to only redraw the characters that changed
+/// since the last flush, so boards don't flicker or fill up the scrollback.
+///
+/// In accessibility mode flush() prints describe() instead, whenever something changed.
+/// Consoles without ANSI support (see Console::ansi) get the whole screen as plain lines then.
+pub struct Screen {
+    width: usize,
+    height: usize,
+    front: Vec<char>,
+    back: Vec<char>,
+    redraw: bool,
+}
+
+impl Screen {
+    pub fn new(width: usize, height: usize) -> Self {
+        Screen {
+            width,
+            height,
+            front: vec![' '; width * height],
+            back: vec![' '; width * height],
+            redraw: true,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Fills the screen with spaces.
+    pub fn clear(&mut self) {
+        self.back.fill(' ');
+    }
+
+    /// The character at x, y (None if out of bounds).
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        (x < self.width && y < self.height).then(|| self.back[y * self.width + x])
+    }
+
+    /// Puts a character at x, y, anything out of bounds is ignored.
+    pub fn draw_char(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width && y < self.height {
+            self.back[y * self.width + x] = c;
+        }
+    }
+
+    /// Writes text starting at x, y, cut off at the right edge.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str) {
+        for (i, c) in text.chars().enumerate() {
+            self.draw_char(x + i, y, c);
+        }
+    }
+
+    /// Makes the next flush redraw the whole screen, e.g. after other output.
+    pub fn invalidate(&mut self) {
+        self.redraw = true;
+    }
+
+    /// The screen as plain lines of text, "ROW <N>: <TEXT>", leaving out blank rows.
+    pub fn describe(&self) -> String {
+        let mut out = String::new();
+        for (y, row) in self.back.chunks(self.width.max(1)).enumerate() {
+            let text: String = row.iter().collect();
+            let text = text.trim_end();
+            if !text.is_empty() {
+                let _ = writeln!(out, "ROW {}: {}", y + 1, text);
+            }
+        }
+        out
+    }
+
+    fn render_plain(&self) -> String {
+        self.back
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Draws what changed since the last flush to the terminal.
+    pub fn flush(&mut self) {
+        if is_accessible() || !console::ansi() {
+            if self.redraw || self.front != self.back {
+                if is_accessible() {
+                    say!("{}", self.describe());
+                } else {
+                    say!("{}", self.render_plain());
+                }
+                self.front.copy_from_slice(&self.back);
+                self.redraw = false;
+            }
+            return;
+        }
+
+        let mut out = String::new();
+
+        if self.redraw {
+            out.push_str("\x1b[2J");
+        }
+
+        for y in 0..self.height {
+            let mut x = 0;
+            while x < self.width {
+                let i = y * self.width + x;
+                if !self.redraw && self.front[i] == self.back[i] {
+                    x += 1;
+                    continue;
+                }
+                // one cursor move for each run of changed characters
+                let _ = write!(out, "\x1b[{};{}H", y + 1, x + 1);
+                while x < self.width {
+                    let i = y * self.width + x;
+                    if !self.redraw && self.front[i] == self.back[i] {
+                        break;
+                    }
+                    out.push(self.back[i]);
+                    x += 1;
+                }
+            }
+        }
+
+        let _ = write!(out, "\x1b[{};1H", self.height + 1);
+        console::write(&out);
+
+        self.front.copy_from_slice(&self.back);
+        self.redraw = false;
+    }
+}
+
+#[cfg(feature = "alt-screen")]
+static GAME_SCREEN: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Switches the terminal to its alternate screen for the game, like full-screen programs do.
+///
+/// The user's scrollback is left alone and comes back with leave_game_screen.
+/// Does nothing if stdout isn't a terminal, in accessibility mode or if already switched.
+#[cfg(feature = "alt-screen")]
+pub fn enter_game_screen() {
+    use std::{io::IsTerminal, sync::atomic::Ordering};
+
+    let supported = std::io::stdout().is_terminal()
+        && std::env::var("TERM").map_or(true, |t| t != "dumb")
+        && !is_accessible();
+    if supported && !GAME_SCREEN.swap(true, Ordering::SeqCst) {
+        // switch screens and move to the top left
+        write_terminal("\x1b[?1049h\x1b[H");
+    }
+}
+
+/// Switches back from the game screen to the normal one, does nothing if not switched.
+#[cfg(feature = "alt-screen")]
+pub fn leave_game_screen() {
+    if GAME_SCREEN.swap(false, std::sync::atomic::Ordering::SeqCst) {
+        write_terminal("\x1b[?1049l");
+    }
+}
+
+#[cfg(feature = "alt-screen")]
+fn write_terminal(codes: &str) {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout().lock();
+    stdout
+        .write_all(codes.as_bytes())
+        .and_then(|_| stdout.flush())
+        .expect("Failed to write to stdout!");
+}
+
+/// Keeps the game screen on while it's alive, leaving it when dropped,
+/// also when the game panics or is quit.
+#[cfg(feature = "alt-screen")]
+#[must_use = "the game screen is left as soon as this is dropped"]
+pub struct GameScreen(());
+
+#[cfg(feature = "alt-screen")]
+impl GameScreen {
+    pub fn enter() -> Self {
+        enter_game_screen();
+        GameScreen(())
+    }
+}
+
+#[cfg(feature = "alt-screen")]
+impl Drop for GameScreen {
+    fn drop(&mut self) {
+        leave_game_screen();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{accessibility, console::ScriptedConsole};
+
+    #[test]
+    fn flushes_plain_text_without_ansi() {
+        accessibility::pin_accessible(Some(false));
+        let scripted = ScriptedConsole::new(Vec::<String>::new());
+        let transcript = scripted.transcript();
+        let previous = console::set_console(Box::new(scripted));
+
+        let mut screen = Screen::new(3, 2);
+        screen.draw_text(0, 0, "XO");
+        screen.flush();
+        // nothing changed, nothing printed
+        screen.flush();
+        screen.draw_char(2, 1, 'X');
+        screen.flush();
+        console::set_console(previous);
+
+        assert_eq!(transcript.text(), "XO\n\nXO\n  X\n");
+    }
+}
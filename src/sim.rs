@@ -0,0 +1,220 @@
+use std::{error::Error, fmt};
+
+use crate::lang::{message, message_with, Message};
+
+/// A season of the simulated year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// The season after this one, Winter is followed by Spring.
+    pub fn next(self) -> Self {
+        match self {
+            Season::Spring => Season::Summer,
+            Season::Summer => Season::Autumn,
+            Season::Autumn => Season::Winter,
+            Season::Winter => Season::Spring,
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(message(match self {
+            Season::Spring => Message::SpringSeason,
+            Season::Summer => Message::SummerSeason,
+            Season::Autumn => Message::AutumnSeason,
+            Season::Winter => Message::WinterSeason,
+        }))
+    }
+}
+
+/// Simulated time for rulership games, counted from day 1 of spring in year 1.
+///
+/// Displays as "DAY 5 OF SPRING, YEAR 3".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameCalendar {
+    year: u32,
+    season: Season,
+    day: u32,
+    days_per_season: u32,
+}
+
+impl GameCalendar {
+    /// Panics if "days_per_season" is 0.
+    pub fn new(days_per_season: u32) -> Self {
+        assert!(days_per_season > 0, "a season needs at least one day");
+        GameCalendar {
+            year: 1,
+            season: Season::Spring,
+            day: 1,
+            days_per_season,
+        }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn season(&self) -> Season {
+        self.season
+    }
+
+    /// Day within the season, starting at 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Moves on one day, into the next season (and year) after its last day.
+    pub fn next_day(&mut self) {
+        if self.day == self.days_per_season {
+            self.next_season();
+        } else {
+            self.day += 1;
+        }
+    }
+
+    /// Moves on to day 1 of the next season, into the next year after winter.
+    pub fn next_season(&mut self) {
+        self.day = 1;
+        self.season = self.season.next();
+        if self.season == Season::Spring {
+            self.year += 1;
+        }
+    }
+
+    /// Moves on one year, keeping the season and day.
+    pub fn next_year(&mut self) {
+        self.year += 1;
+    }
+
+    /// Prints e.g. "IN THE YEAR 3 OF YOUR REIGN".
+    pub fn print_reign(&self) {
+        say!("{}", self.render_reign());
+    }
+
+    pub fn render_reign(&self) -> String {
+        message_with(Message::YearOfReign, &[&self.year])
+    }
+}
+
+impl fmt::Display for GameCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&message_with(
+            Message::CalendarDate,
+            &[&self.day, &self.season, &self.year],
+        ))
+    }
+}
+
+/// Why a ledger refused a change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerError {
+    /// Spending more than there is.
+    NotEnough { name: String, have: u64 },
+    /// The quantity wouldn't fit in a u64.
+    TooMany { name: String },
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LedgerError::NotEnough { name, have } => {
+                f.write_str(&message_with(Message::NotEnough, &[have, name]))
+            }
+            LedgerError::TooMany { name } => f.write_str(&message_with(Message::TooMany, &[name])),
+        }
+    }
+}
+
+impl Error for LedgerError {}
+
+/// Named quantities for trading and economy games, e.g. bushels, acres and dollars.
+///
+/// Quantities never go negative, names are matched ignoring case and shown in capitals.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    entries: Vec<(String, u64)>,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        Ledger::default()
+    }
+
+    /// Sets the quantity, adding it to the ledger if it's new.
+    pub fn set(&mut self, name: &str, amount: u64) {
+        *self.entry(name) = amount;
+    }
+
+    /// The quantity, 0 if it isn't in the ledger.
+    pub fn get(&self, name: &str) -> u64 {
+        self.entries
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map_or(0, |(_, amount)| *amount)
+    }
+
+    pub fn can_afford(&self, name: &str, amount: u64) -> bool {
+        self.get(name) >= amount
+    }
+
+    /// Adds to the quantity and returns the new amount.
+    pub fn add(&mut self, name: &str, amount: u64) -> Result<u64, LedgerError> {
+        let total = self
+            .get(name)
+            .checked_add(amount)
+            .ok_or_else(|| LedgerError::TooMany {
+                name: name.to_uppercase(),
+            })?;
+        self.set(name, total);
+        Ok(total)
+    }
+
+    /// Takes from the quantity and returns what's left, leaving it unchanged if there isn't enough.
+    pub fn spend(&mut self, name: &str, amount: u64) -> Result<u64, LedgerError> {
+        let have = self.get(name);
+        let left = have
+            .checked_sub(amount)
+            .ok_or_else(|| LedgerError::NotEnough {
+                name: name.to_uppercase(),
+                have,
+            })?;
+        self.set(name, left);
+        Ok(left)
+    }
+
+    /// Prints every quantity, one per line, e.g. "BUSHELS      2800".
+    pub fn print_report(&self) {
+        say!("{}", self.render_report());
+    }
+
+    pub fn render_report(&self) -> String {
+        let width = self.entries.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
+        self.entries
+            .iter()
+            .map(|(name, amount)| format!("{name:<width$}  {amount:>10}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn entry(&mut self, name: &str) -> &mut u64 {
+        let i = match self
+            .entries
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            Some(i) => i,
+            None => {
+                self.entries.push((name.to_uppercase(), 0));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[i].1
+    }
+}
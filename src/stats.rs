@@ -0,0 +1,165 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::lang::{message, message_with, Message};
+
+/// The outcome of one play of a game.
+pub struct RunRecord {
+    pub game: String,
+    /// e.g. "WIN", "LOSS" or "QUIT".
+    pub result: String,
+    pub score: i64,
+    pub duration: Duration,
+}
+
+impl RunRecord {
+    pub fn new(game: &str, result: &str, score: i64, duration: Duration) -> Self {
+        RunRecord {
+            game: game.to_uppercase(),
+            result: result.to_uppercase(),
+            score,
+            duration,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\n",
+            clean(&self.game),
+            clean(&self.result),
+            self.score,
+            self.duration.as_secs()
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        Some(RunRecord {
+            game: fields.next()?.to_string(),
+            result: fields.next()?.to_string(),
+            score: fields.next()?.parse().ok()?,
+            duration: Duration::from_secs(fields.next()?.parse().ok()?),
+        })
+    }
+}
+
+fn clean(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
+
+/// A local file collecting the records of every game played, across all games.
+pub struct StatsFile {
+    path: PathBuf,
+}
+
+impl StatsFile {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        StatsFile { path: path.into() }
+    }
+
+    /// The file in MORRISTOWN_STATS, or ".morristown_stats" in the home directory.
+    pub fn shared() -> Self {
+        if let Some(path) = env::var_os("MORRISTOWN_STATS") {
+            return StatsFile::new(path);
+        }
+        let home = env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        StatsFile::new(home.join(".morristown_stats"))
+    }
+
+    /// Adds a record to the end of the file.
+    pub fn append(&self, record: &RunRecord) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(record.to_line().as_bytes())
+    }
+
+    /// All records of a game, oldest first.
+    pub fn records(&self, game: &str) -> io::Result<Vec<RunRecord>> {
+        let game = game.to_uppercase();
+        let content = match fs::read_to_string(&self.path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        Ok(content
+            .lines()
+            .filter_map(RunRecord::from_line)
+            .filter(|r| r.game == game)
+            .collect())
+    }
+
+    /// Prints games played, results, best and average score and time played for a game.
+    pub fn print_lifetime_stats(&self, game: &str) {
+        say!("{}", self.render_lifetime_stats(game));
+    }
+
+    /// The lifetime stats of print_lifetime_stats as text.
+    pub fn render_lifetime_stats(&self, game: &str) -> String {
+        let records = self.records(game).unwrap_or_default();
+
+        let mut out = format!(
+            "\n{}\n",
+            message_with(Message::LifetimeStats, &[&game.to_uppercase()])
+        );
+        if records.is_empty() {
+            out.push_str(message(Message::NoGamesPlayed));
+            return out;
+        }
+
+        let _ = writeln!(
+            out,
+            "{}",
+            message_with(Message::GamesPlayed, &[&records.len()])
+        );
+
+        let mut results: BTreeMap<&str, usize> = BTreeMap::new();
+        for r in &records {
+            *results.entry(r.result.as_str()).or_default() += 1;
+        }
+        for (result, count) in results {
+            let _ = writeln!(out, "{result}: {count}");
+        }
+
+        let best = records.iter().map(|r| r.score).max().unwrap_or_default();
+        let total: i64 = records.iter().map(|r| r.score).sum();
+        let _ = writeln!(out, "{}", message_with(Message::BestScore, &[&best]));
+        let _ = writeln!(
+            out,
+            "{}",
+            message_with(Message::AverageScore, &[&(total / records.len() as i64)])
+        );
+
+        let secs: u64 = records.iter().map(|r| r.duration.as_secs()).sum();
+        let _ = writeln!(
+            out,
+            "{}",
+            message_with(
+                Message::TimePlayed,
+                &[&(secs / 3600), &(secs / 60 % 60), &(secs % 60)]
+            )
+        );
+        out
+    }
+}
+
+/// Appends a record to the shared stats file.
+pub fn record_run(record: &RunRecord) -> io::Result<()> {
+    StatsFile::shared().append(record)
+}
+
+/// Prints the lifetime stats of a game from the shared stats file.
+pub fn print_lifetime_stats(game: &str) {
+    StatsFile::shared().print_lifetime_stats(game);
+}
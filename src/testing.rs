@@ -0,0 +1,198 @@
+use std::{env, fs, path::Path};
+
+use crate::{
+    accessibility, console,
+    console::ScriptedConsole,
+    lang::{self, Lang},
+    quit, rng,
+};
+
+/// Seed of the random numbers in assert_game_transcript.
+pub const TRANSCRIPT_SEED: u64 = 0;
+
+/// Unchanged lines shown around each difference.
+const CONTEXT: usize = 2;
+
+/// Plays a whole game with scripted answers and checks its transcript against a file,
+/// e.g. a sample run of the original.
+///
+/// The transcript has both output and answers, like it would look on a terminal.
+/// Panics with a line diff if they differ.
+///
+/// The game is played in English with accessibility mode off, whatever MORRISTOWN_ACCESSIBLE says,
+/// and without ANSI styling. Random numbers are seeded with TRANSCRIPT_SEED, so they're the same
+/// on every run. The game starts without quit word, help or middleware of the calling thread.
+/// All of these are put back afterwards, changing them in the game doesn't affect other tests.
+///
+/// Run with MORRISTOWN_UPDATE_TRANSCRIPTS=1 to write the transcript to the file instead.
+pub fn assert_game_transcript<F, I, S, P>(game: F, input_script: I, expected_output_file: P)
+where
+    F: FnOnce(),
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+    P: AsRef<Path>,
+{
+    let path = expected_output_file.as_ref();
+
+    let scripted = ScriptedConsole::new(input_script);
+    let transcript = scripted.transcript();
+    let previous = console::set_console(Box::new(scripted));
+    let middleware = console::take_middleware();
+    let help = console::help_source();
+    console::set_help(None);
+    let quit_word = quit::quit_word();
+    quit::set_quit_word(None);
+    let rng_state = rng::state();
+    rng::seed(TRANSCRIPT_SEED);
+    let pinned_lang = lang::pin_lang(Some(Lang::English));
+    let pinned_accessible = accessibility::pin_accessible(Some(false));
+
+    quit::run_game(game);
+
+    accessibility::pin_accessible(pinned_accessible);
+    lang::pin_lang(pinned_lang);
+    rng::seed(rng_state);
+    quit::set_quit_word(quit_word.as_deref());
+    console::set_help_source(help);
+    console::set_middleware(middleware);
+    console::set_console(previous);
+    let actual = transcript.text();
+
+    if env::var_os("MORRISTOWN_UPDATE_TRANSCRIPTS").is_some_and(|v| v == "1") {
+        fs::write(path, &actual).expect("Failed to write transcript!");
+        return;
+    }
+
+    let expected = fs::read_to_string(path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to read transcript {}: {e}\n\
+             (run with MORRISTOWN_UPDATE_TRANSCRIPTS=1 to create it)",
+            path.display()
+        )
+    });
+    if actual != expected {
+        panic!(
+            "transcript differs from {} (- expected, + actual):\n{}",
+            path.display(),
+            diff(&expected, &actual)
+        );
+    }
+}
+
+/// Line diff of two texts, only the changes and the lines around them.
+fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // longest common subsequence lengths of the tails
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+    if lines.iter().all(|(c, _)| *c == ' ') {
+        // only line endings differ
+        return format!("{expected:?}\n{actual:?}");
+    }
+
+    let near_change = |k: usize| {
+        let start = k.saturating_sub(CONTEXT);
+        let end = (k + CONTEXT + 1).min(lines.len());
+        lines[start..end].iter().any(|(c, _)| *c != ' ')
+    };
+    let mut out = Vec::new();
+    let mut skipped = false;
+    for (k, (c, line)) in lines.iter().enumerate() {
+        if near_change(k) {
+            out.push(format!("{c} {line}"));
+            skipped = false;
+        } else if !skipped {
+            out.push("  ...".to_string());
+            skipped = true;
+        }
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::console::Uppercase;
+
+    #[test]
+    fn transcripts_are_reproducible_and_isolated() {
+        let path = env::temp_dir().join(format!("morristown-transcript-{}", std::process::id()));
+        let game = || {
+            say!("{} quiet", rng::rnd_range(1..=1000));
+            say!("{}", crate::prompt_string("NAME?"));
+            lang::set_lang(Lang::German);
+        };
+        fs::write(&path, "").unwrap();
+
+        console::add_middleware(Box::new(Uppercase));
+        quit::set_quit_word(Some("BOB"));
+        rng::seed(1);
+        let first = run_transcript(game, &path);
+        assert_eq!(rng::state(), 1);
+        assert!(quit::is_quit_word("BOB"));
+        assert_eq!(console::take_middleware().len(), 1);
+        assert_eq!(lang::lang(), Lang::English);
+
+        rng::seed(2);
+        assert_eq!(run_transcript(game, &path), first);
+        // not uppercased, and BOB doesn't quit
+        assert!(first.ends_with(" quiet\nNAME?\nbob\nBOB\n"), "{first}");
+        let _ = fs::remove_file(&path);
+    }
+
+    fn run_transcript<F: FnOnce()>(game: F, path: &Path) -> String {
+        env::set_var("MORRISTOWN_UPDATE_TRANSCRIPTS", "1");
+        assert_game_transcript(game, ["bob"], path);
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn diff_shows_changes_with_context() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let actual = "1\n2\n3\n4\nFIVE\n6\n7\n8\n9\n10\nELEVEN\n";
+        assert_eq!(
+            diff(expected, actual),
+            "  ...\n  3\n  4\n- 5\n+ FIVE\n  6\n  7\n  ...\n  9\n  10\n+ ELEVEN"
+        );
+    }
+
+    #[test]
+    fn diff_of_added_and_removed_lines() {
+        assert_eq!(diff("A\nB\n", "B\nC\n"), "- A\n  B\n+ C");
+        assert_eq!(diff("", "A"), "+ A");
+        assert_eq!(diff("A", ""), "- A");
+    }
+
+    #[test]
+    fn diff_of_line_endings() {
+        assert_eq!(diff("A\r\nB", "A\nB"), "\"A\\r\\nB\"\n\"A\\nB\"");
+    }
+}
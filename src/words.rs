@@ -0,0 +1,306 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use crate::rng;
+
+/// The word list of the original HANGMAN program.
+pub const CLASSIC_WORDS: &[&str] = &[
+    "GUM",
+    "SIN",
+    "FOR",
+    "CRY",
+    "LUG",
+    "BYE",
+    "FLY",
+    "UGLY",
+    "EACH",
+    "FROM",
+    "WORK",
+    "TALK",
+    "WITH",
+    "SELF",
+    "PIZZA",
+    "THING",
+    "FEIGN",
+    "FIEND",
+    "ELBOW",
+    "FAULT",
+    "DIRTY",
+    "BUDGET",
+    "SPIRIT",
+    "QUAINT",
+    "MAIDEN",
+    "ESCORT",
+    "PICKAX",
+    "EXAMPLE",
+    "TENSION",
+    "QUININE",
+    "KIDNEY",
+    "REPLICA",
+    "SLEEPER",
+    "TRIANGLE",
+    "KANGAROO",
+    "MAHOGANY",
+    "SERGEANT",
+    "SEQUENCE",
+    "MOUSTACHE",
+    "DANGEROUS",
+    "SCIENTIST",
+    "DIFFERENT",
+    "QUIESCENT",
+    "MAGISTRATE",
+    "ERRONEOUSLY",
+    "LOUDSPEAKER",
+    "PHYTOTOXIC",
+    "MATRIMONIAL",
+    "PARASYMPATHOMIMETIC",
+    "THIGMOTROPISM",
+];
+
+#[cfg(feature = "large-word-list")]
+const LARGE_WORDS: &str = include_str!("../data/words_large.txt");
+
+/// All embedded words.
+///
+/// Enable the "large-word-list" feature for a few hundred more common words.
+pub fn word_list() -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut words = CLASSIC_WORDS.to_vec();
+    #[cfg(feature = "large-word-list")]
+    words.extend(LARGE_WORDS.lines().filter(|l| !l.is_empty()));
+    words
+}
+
+/// Embedded words with an amount of letters in the specified range.
+pub fn words_with_length(length: RangeInclusive<usize>) -> Vec<&'static str> {
+    word_list()
+        .into_iter()
+        .filter(|w| length.contains(&w.len()))
+        .collect()
+}
+
+/// Picks a random embedded word.
+pub fn random_word() -> &'static str {
+    let words = word_list();
+    words[rng::below(words.len())]
+}
+
+/// Picks a random embedded word with an amount of letters in the specified range.
+///
+/// Returns None if no word fits.
+pub fn random_word_with_length(length: RangeInclusive<usize>) -> Option<&'static str> {
+    let words = words_with_length(length);
+    if words.is_empty() {
+        return None;
+    }
+    Some(words[rng::below(words.len())])
+}
+
+/// Hides the letters of "word" that haven't been guessed yet with a "-".
+///
+/// Anything that isn't a letter is always shown. Letters match regardless of case.
+pub fn mask_word(word: &str, guessed: &HashSet<char>) -> String {
+    word.chars()
+        .map(|c| {
+            let c = upper(c);
+            if !c.is_alphabetic() || guessed.iter().any(|g| upper(*g) == c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// The uppercase letter, kept a single char (unlike "ß" to "SS").
+fn upper(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+/// Puts a space between each letter, e.g. "-A--E" becomes "- A - - E".
+pub fn spaced(word: &str) -> String {
+    let mut out = String::with_capacity(word.len() * 2);
+    for (i, c) in word.chars().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Outcome of guessing a letter.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Guess {
+    /// The letter is in the word this many times.
+    Hit(usize),
+    Miss,
+    AlreadyGuessed,
+}
+
+/// Tracks the letters revealed of a secret word, Hangman style.
+pub struct RevealedWord {
+    word: String,
+    guessed: HashSet<char>,
+}
+
+impl RevealedWord {
+    pub fn new(word: &str) -> Self {
+        RevealedWord {
+            word: word.chars().map(upper).collect(),
+            guessed: HashSet::new(),
+        }
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn guessed(&self) -> &HashSet<char> {
+        &self.guessed
+    }
+
+    pub fn guess(&mut self, letter: char) -> Guess {
+        let letter = upper(letter);
+        if !self.guessed.insert(letter) {
+            return Guess::AlreadyGuessed;
+        }
+        match self.word.chars().filter(|c| *c == letter).count() {
+            0 => Guess::Miss,
+            n => Guess::Hit(n),
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.word
+            .chars()
+            .all(|c| !c.is_alphabetic() || self.guessed.contains(&c))
+    }
+
+    pub fn masked(&self) -> String {
+        mask_word(&self.word, &self.guessed)
+    }
+
+    /// Prints the word with unrevealed letters hidden, spaced out like the originals.
+    pub fn print(&self) {
+        say!("{}", self.render());
+    }
+
+    /// The word as print() shows it.
+    pub fn render(&self) -> String {
+        spaced(&self.masked())
+    }
+}
+
+const ONES: [&str; 20] = [
+    "ZERO",
+    "ONE",
+    "TWO",
+    "THREE",
+    "FOUR",
+    "FIVE",
+    "SIX",
+    "SEVEN",
+    "EIGHT",
+    "NINE",
+    "TEN",
+    "ELEVEN",
+    "TWELVE",
+    "THIRTEEN",
+    "FOURTEEN",
+    "FIFTEEN",
+    "SIXTEEN",
+    "SEVENTEEN",
+    "EIGHTEEN",
+    "NINETEEN",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "TWENTY", "THIRTY", "FORTY", "FIFTY", "SIXTY", "SEVENTY", "EIGHTY", "NINETY",
+];
+
+const SCALES: [&str; 7] = [
+    "",
+    "THOUSAND",
+    "MILLION",
+    "BILLION",
+    "TRILLION",
+    "QUADRILLION",
+    "QUINTILLION",
+];
+
+/// Spells out a number, e.g. 123 becomes "ONE HUNDRED TWENTY-THREE".
+pub fn number_to_words(n: i64) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut rest = n.unsigned_abs();
+    let mut groups = Vec::new();
+    let mut scale = 0;
+
+    while rest > 0 {
+        let group = (rest % 1000) as usize;
+        if group > 0 {
+            let mut words = hundreds_to_words(group);
+            if scale > 0 {
+                words.push(' ');
+                words.push_str(SCALES[scale]);
+            }
+            groups.push(words);
+        }
+        rest /= 1000;
+        scale += 1;
+    }
+
+    groups.reverse();
+    let words = groups.join(" ");
+    if n < 0 {
+        format!("MINUS {words}")
+    } else {
+        words
+    }
+}
+
+fn hundreds_to_words(n: usize) -> String {
+    let mut parts = Vec::new();
+    if n >= 100 {
+        parts.push(format!("{} HUNDRED", ONES[n / 100]));
+    }
+    match n % 100 {
+        0 => {}
+        r if r < 20 => parts.push(ONES[r].to_string()),
+        r if r % 10 == 0 => parts.push(TENS[r / 10].to_string()),
+        r => parts.push(format!("{}-{}", TENS[r / 10], ONES[r % 10])),
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_letters_regardless_of_case() {
+        let guessed = HashSet::from(['a', 'P']);
+        assert_eq!(mask_word("apple", &guessed), "APP--");
+        assert_eq!(mask_word("Apple pie!", &guessed), "APP-- P--!");
+    }
+
+    #[test]
+    fn reveals_letters_regardless_of_case() {
+        let mut word = RevealedWord::new("Straße");
+        assert_eq!(word.word(), "STRAßE");
+        assert_eq!(word.guess('s'), Guess::Hit(1));
+        assert_eq!(word.guess('S'), Guess::AlreadyGuessed);
+        assert_eq!(word.guess('é'), Guess::Miss);
+        for letter in "TRAßE".chars() {
+            word.guess(letter);
+        }
+        assert!(word.is_solved());
+        assert_eq!(word.render(), "S T R A ß E");
+    }
+}